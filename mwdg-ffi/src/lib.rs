@@ -11,6 +11,10 @@
 //! extern void mwdg_enter_critical(void);
 //! extern void mwdg_exit_critical(void);
 //! ```
+//!
+//! The time source can alternatively be supplied at run time through
+//! `mwdg_init_with_clock`, in which case `mwdg_get_time_milliseconds` is
+//! never called (the symbol must still be resolvable at link time).
 #![no_std]
 
 #[cfg(feature = "pack")]
@@ -20,7 +24,7 @@ use core::cell::UnsafeCell;
 use core::pin::Pin;
 use core::ptr;
 
-use mwdg::{WatchdogNode, WatchdogRegistry};
+use mwdg::{CriticalSection, Cursor, Health, NodeStatus, WatchdogNode, WatchdogRegistry};
 
 unsafe extern "C" {
     /// User-provided function that returns the current time in milliseconds.
@@ -63,9 +67,74 @@ pub struct mwdg_node {
     /// benefit when identifying expired nodes via [`mwdg_get_next_expired`].
     id: u32,
 
+    /// Early-warning interval in milliseconds. `0` disables the warning.
+    warn_interval_ms: u32,
+
+    /// Smallest remaining time (ms) observed by full scans since the node
+    /// was added.
+    min_margin_ms: u32,
+
+    /// Minimum interval (ms) expected between two consecutive feeds.
+    min_interval_ms: u32,
+
+    /// Interval (ms) between the last two feeds.
+    feed_gap_ms: u32,
+
+    /// Sum of the intervals between consecutive feeds since the node was
+    /// added.
+    accumulated_gap_ms: u32,
+
+    /// Number of feeds since the node was added.
+    feed_count: u32,
+
+    /// Number of full scans that found the node expired.
+    expiry_count: u32,
+
+    /// Whether the node is unregistered instead of latching once it expires.
+    one_shot: bool,
+
+    /// Whether monitoring of the node is suspended.
+    paused: bool,
+
+    /// Whether the node has been fed since it was added or resumed.
+    fed: bool,
+
+    /// Whether the node is currently linked into the registry.
+    linked: bool,
+
+    /// Whether the node tracks an absolute deadline instead of a heartbeat.
+    deadline: bool,
+
+    /// User-assigned priority / severity class. Defaults to `0`.
+    priority: u8,
+
+    /// Group (subsystem) the node belongs to. Defaults to `0`.
+    group: u8,
+
+    /// Consecutive healthy scans since the node was last seen unhealthy.
+    healthy_streak: u16,
+
+    /// Healthy scans required before a degraded node recovers.
+    recover_after: u16,
+
+    /// Checks since the node was last fed (miss-budget nodes only).
+    missed_checks: u16,
+
+    /// Missed checks before expiry, or `0` for a time-based node.
+    max_missed: u16,
+
+    /// Opaque storage for the diagnostic name of the `names` feature (a Rust
+    /// string slice). All-zero means no name.
+    #[cfg(feature = "names")]
+    name: [usize; 2],
+
     /// Intrusive linked-list pointer to the next registered watchdog.
     /// Null if this is the tail of the list.
     next: *mut mwdg_node,
+
+    /// Intrusive linked-list pointer to the previous registered watchdog.
+    /// Null if this is the head of the list.
+    prev: *mut mwdg_node,
 }
 
 impl Default for mwdg_node {
@@ -74,16 +143,38 @@ impl Default for mwdg_node {
             timeout_interval_ms: 0,
             last_touched_timestamp_ms: 0,
             id: 0,
+            warn_interval_ms: 0,
+            min_margin_ms: u32::MAX,
+            min_interval_ms: 0,
+            feed_gap_ms: u32::MAX,
+            accumulated_gap_ms: 0,
+            feed_count: 0,
+            expiry_count: 0,
+            one_shot: false,
+            paused: false,
+            fed: false,
+            linked: false,
+            deadline: false,
+            priority: 0,
+            group: 0,
+            healthy_streak: u16::MAX,
+            recover_after: 0,
+            missed_checks: 0,
+            max_missed: 0,
+            #[cfg(feature = "names")]
+            name: [0; 2],
             next: ptr::null_mut(),
+            prev: ptr::null_mut(),
         }
     }
 }
 
-// `WatchdogNode` is `#[repr(C)]` with fields (u32, u32, u32, *mut Self,
-// PhantomPinned). `PhantomPinned` is a ZST with alignment 1, so it does not
-// affect the `repr(C)` layout. The first four fields are identical in type and
-// order to `mwdg_node`, therefore the two types share the same size and
-// alignment. Casting `*mut mwdg_node` ↔ `*mut WatchdogNode` is sound.
+// `WatchdogNode` (the `u32` instantiation of `WatchdogNodeT`) is `#[repr(C)]`
+// and declares the same fields as `mwdg_node`, in the same order and with the
+// same types, followed by a `PhantomPinned` marker. `PhantomPinned` is a ZST
+// with alignment 1, so it does not affect the `repr(C)` layout, therefore the
+// two types share the same size and alignment. Casting `*mut mwdg_node` ↔
+// `*mut WatchdogNode` is sound.
 const _: () = assert!(
     core::mem::size_of::<mwdg_node>() == core::mem::size_of::<WatchdogNode>(),
     "mwdg_node and WatchdogNode must have the same size"
@@ -93,6 +184,32 @@ const _: () = assert!(
     "mwdg_node and WatchdogNode must have the same alignment"
 );
 
+/// Status record of one watchdog, filled in by [`mwdg_snapshot`].
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct mwdg_status {
+    /// User-assigned identifier of the node.
+    pub id: u32,
+    /// Configured timeout interval in milliseconds.
+    pub timeout_ms: u32,
+    /// Time left before the node expires, including the grace period;
+    /// `0` once it counts as expired.
+    pub remaining_ms: u32,
+    /// `1` if the node counted as expired at the snapshot time, else `0`.
+    pub expired: i32,
+}
+
+impl From<NodeStatus<u32>> for mwdg_status {
+    fn from(status: NodeStatus<u32>) -> Self {
+        Self {
+            id: status.id,
+            timeout_ms: status.timeout_ms,
+            remaining_ms: status.remaining_ms,
+            expired: i32::from(status.expired),
+        }
+    }
+}
+
 /// Cast a `*mut mwdg_node` to `*mut WatchdogNode`.
 ///
 /// # Safety
@@ -123,37 +240,109 @@ unsafe fn pin_node_mut<'a>(ptr: *mut mwdg_node) -> Option<Pin<&'a mut WatchdogNo
     unsafe { Some(Pin::new_unchecked(&mut *cast_node(ptr))) }
 }
 
-/// Wrapper to allow `WatchdogRegistry` in a `static`.
+/// Global state of the C shim: the registry plus the configured time source.
+struct State {
+    registry: WatchdogRegistry,
+    /// Clock registered via [`mwdg_init_with_clock`]. `None` selects the
+    /// user-provided `mwdg_get_time_milliseconds` symbol.
+    clock: Option<extern "C" fn() -> u32>,
+    /// Cursor last handed back to C by one of the `mwdg_get_next_*`
+    /// iterators.
+    cursor: Cursor,
+}
+
+impl State {
+    /// Resume the C cursor `raw`. When it is the position last handed out,
+    /// the stored [`Cursor`] is reused so the registry can skip validating
+    /// it; any other pointer is validated by walking the list.
+    fn resume_cursor(&self, raw: *mut mwdg_node) -> Cursor {
+        // SAFETY: only the address is converted, nothing is dereferenced.
+        let raw = unsafe { cast_node(raw).cast_const() };
+        if !raw.is_null() && self.cursor.into_raw() == raw {
+            self.cursor
+        } else {
+            Cursor::from_raw(raw)
+        }
+    }
+
+    /// Read the current time from the configured time source.
+    fn now(&self) -> u32 {
+        match self.clock {
+            Some(clock) => clock(),
+            None => unsafe { mwdg_get_time_milliseconds() },
+        }
+    }
+}
+
+/// Wrapper to allow the global [`State`] in a `static`.
 ///
 /// # Safety
 /// All access to the inner state is protected by the user-provided
 /// critical section callbacks (enter/exit). `mwdg_init` must be called
 /// once from a single context before any other function.
-struct GlobalState(UnsafeCell<WatchdogRegistry>);
+struct GlobalState(UnsafeCell<State>);
 
 // SAFETY: All access is gated by user-provided critical section.
 unsafe impl Sync for GlobalState {}
 
-static STATE: GlobalState = GlobalState(UnsafeCell::new(WatchdogRegistry::new()));
+static STATE: GlobalState = GlobalState(UnsafeCell::new(State {
+    registry: WatchdogRegistry::new(),
+    clock: None,
+    cursor: Cursor::start(),
+}));
 
 impl GlobalState {
     #[allow(clippy::mut_from_ref)]
-    fn as_mut(&self) -> &mut WatchdogRegistry {
+    fn as_mut(&self) -> &mut State {
         unsafe { &mut *self.0.get() }
     }
 
-    fn as_ref(&self) -> &WatchdogRegistry {
+    fn as_ref(&self) -> &State {
         unsafe { &*self.0.get() }
     }
 }
 
+/// Expiry callback registered via [`mwdg_set_expiry_callback`].
+///
+/// Kept outside [`State`] because it is read by [`forward_expiry`] while the
+/// registry inside `State` is mutably borrowed by a scan.
+struct ExpiryCallback(UnsafeCell<Option<extern "C" fn(id: u32)>>);
+
+// SAFETY: Only accessed inside the user-provided critical section.
+unsafe impl Sync for ExpiryCallback {}
+
+static EXPIRY_CALLBACK: ExpiryCallback = ExpiryCallback(UnsafeCell::new(None));
+
+/// Registry on-expire hook forwarding to the C callback, if any.
+fn forward_expiry(id: u32) {
+    // SAFETY: the hook only runs from registry scans, which execute inside
+    // the critical section.
+    if let Some(callback) = unsafe { *EXPIRY_CALLBACK.0.get() } {
+        callback(id);
+    }
+}
+
+/// [`CriticalSection`] backed by the user-provided `mwdg_enter_critical` /
+/// `mwdg_exit_critical` callbacks.
+struct UserCriticalSection;
+
+impl CriticalSection for UserCriticalSection {
+    fn enter(&self) {
+        unsafe { mwdg_enter_critical() };
+    }
+
+    fn exit(&self) {
+        unsafe { mwdg_exit_critical() };
+    }
+}
+
 /// Execute `f` inside the user-provided critical section.
 #[inline]
-fn with_critical_section<R>(f: impl FnOnce(&mut WatchdogRegistry) -> R) -> R {
+fn with_critical_section<R>(f: impl FnOnce(&mut State) -> R) -> R {
     let state = STATE.as_mut();
-    unsafe { mwdg_enter_critical() };
+    UserCriticalSection.enter();
     let result = f(state);
-    unsafe { mwdg_exit_critical() };
+    UserCriticalSection.exit();
     result
 }
 
@@ -167,12 +356,61 @@ fn with_critical_section<R>(f: impl FnOnce(&mut WatchdogRegistry) -> R) -> R {
 /// - Must not be called from multiple threads concurrently.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn mwdg_init() {
-    STATE.as_mut().init();
+    let state = STATE.as_mut();
+    state.registry.init();
+    state.clock = None;
+    unsafe { *EXPIRY_CALLBACK.0.get() = None };
+}
+
+/// Initialize the multi-watchdog subsystem with an explicit time source.
+///
+/// Behaves like [`mwdg_init`], but all subsequent `mwdg_*` calls read the
+/// current time from `clock` instead of the `mwdg_get_time_milliseconds`
+/// symbol. Calling [`mwdg_init`] afterwards switches back to the symbol.
+///
+/// # Parameters
+/// - `clock`: function returning the current time in milliseconds.
+///
+/// # Safety
+/// Same requirements as [`mwdg_init`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_init_with_clock(clock: extern "C" fn() -> u32) {
+    let state = STATE.as_mut();
+    state.registry.init();
+    state.clock = Some(clock);
+    unsafe { *EXPIRY_CALLBACK.0.get() = None };
+}
+
+/// Register a function to be notified when a watchdog expires.
+///
+/// [`mwdg_check`] calls `cb` with the id of the first expired node it finds,
+/// right before the registry latches, so it fires once per latch cycle (and
+/// additionally once for every expired one-shot node). This replaces
+/// polling for the id with [`mwdg_get_next_expired`]. Passing `NULL`
+/// removes the callback; [`mwdg_init`] removes it as well.
+///
+/// # Re-entrancy
+/// `cb` runs inside the critical section, in the context that called
+/// `mwdg_check`. It must be short and must not call back into any `mwdg_*`
+/// function — typically it records the id or signals a task.
+///
+/// # Parameters
+/// - `cb`: function receiving the expired node's id, or `NULL`.
+///
+/// # Safety
+/// - `mwdg_init` must have been called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_set_expiry_callback(cb: Option<extern "C" fn(id: u32)>) {
+    with_critical_section(|state| {
+        // SAFETY: inside the critical section.
+        unsafe { *EXPIRY_CALLBACK.0.get() = cb };
+        state.registry.set_on_expire(forward_expiry);
+    });
 }
 
 /// Register a software watchdog with the given timeout.
 ///
-/// Initializes the watchdog fields and prepends it to the global list.
+/// Initializes the watchdog fields and appends it to the global list.
 /// The watchdog's `last_touched_timestamp_ms` is set to the current time.
 ///
 /// If the node is already in the list (detected by pointer comparison), the
@@ -193,9 +431,9 @@ pub unsafe extern "C" fn mwdg_add(wdg: *mut mwdg_node, timeout_ms: u32) {
         return;
     };
 
-    with_critical_section(|registry| {
-        let now = unsafe { mwdg_get_time_milliseconds() };
-        registry.add(pinned, timeout_ms, now);
+    with_critical_section(|state| {
+        let now = state.now();
+        state.registry.add(pinned, timeout_ms, now);
     });
 }
 
@@ -213,11 +451,67 @@ pub unsafe extern "C" fn mwdg_remove(wdg: *mut mwdg_node) {
         return;
     };
 
-    with_critical_section(|registry| {
-        registry.remove(pinned);
+    with_critical_section(|state| {
+        state.registry.remove(pinned);
     });
 }
 
+/// Remove a previously registered watchdog given as an untyped handle.
+///
+/// Same as [`mwdg_remove`], but accepts the `void *` handles some C code
+/// stores instead of `struct mwdg_node *`, and reports whether the node was
+/// actually registered.
+///
+/// # Returns
+/// - `1` if the node was found and removed.
+/// - `0` if `wdg` is null or the node is not registered.
+///
+/// # Safety
+/// - `wdg` must be either null or a valid pointer to an `mwdg_node`.
+/// - `mwdg_init` must have been called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_remove_ptr(wdg: *mut core::ffi::c_void) -> i32 {
+    let Some(mut pinned) = (unsafe { pin_node_mut(wdg.cast::<mwdg_node>()) }) else {
+        return 0;
+    };
+
+    with_critical_section(|state| {
+        let found = state.registry.contains(pinned.as_ref());
+        state.registry.remove(pinned.as_mut());
+        i32::from(found)
+    })
+}
+
+/// Remove all registered watchdogs from the global list.
+///
+/// Every node's `next` pointer is cleared so the nodes can safely be
+/// re-added with [`mwdg_add`]. Unlike [`mwdg_init`], the subsystem stays
+/// initialized and the expiration latch is left untouched.
+///
+/// # Safety
+/// - `mwdg_init` must have been called.
+/// - All registered `mwdg_node` pointers must still be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_remove_all() {
+    with_critical_section(|state| {
+        state.registry.remove_all();
+    });
+}
+
+/// Get the number of registered watchdogs.
+///
+/// Walks the global list inside the critical section.
+///
+/// # Safety
+/// - `mwdg_init` must have been called.
+/// - All registered `mwdg_node` pointers must still be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_count() -> u32 {
+    with_critical_section(|state| {
+        u32::try_from(state.registry.iter_ids().count()).unwrap_or(u32::MAX)
+    })
+}
+
 /// Feed (touch) a watchdog, resetting its timestamp to the current time.
 ///
 /// Must be called periodically by the owning task to signal liveness.
@@ -231,12 +525,81 @@ pub unsafe extern "C" fn mwdg_feed(wdg: *mut mwdg_node) {
         return;
     };
 
+    with_critical_section(|state| {
+        WatchdogRegistry::feed(pinned, state.now());
+    });
+}
+
+/// Feed (touch) a watchdog at an explicit timestamp.
+///
+/// Like [`mwdg_feed`], but the node's timestamp is set to `now` instead of
+/// reading the clock, so a batch of feeds can share one cached timestamp
+/// without per-node clock calls or skew. `now` must come from the same time
+/// source that [`mwdg_check`] uses.
+///
+/// # Parameters
+/// - `wdg`: pointer to a caller-owned [`mwdg_node`].
+/// - `now`: the timestamp in milliseconds to record as the last feed.
+///
+/// # Safety
+/// - `wdg` must be either null or a valid pointer to a registered
+///   `mwdg_node`.
+/// - `mwdg_init` must have been called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_feed_at(wdg: *mut mwdg_node, now: u32) {
+    let Some(pinned) = (unsafe { pin_node_mut(wdg) }) else {
+        return;
+    };
+
     with_critical_section(|_| {
-        let now = unsafe { mwdg_get_time_milliseconds() };
         WatchdogRegistry::feed(pinned, now);
     });
 }
 
+/// Feed (touch) a registered watchdog identified by its id.
+///
+/// Intended for consumers that only know the task id, e.g. a heartbeat
+/// queue. Walks the list and resets the timestamp of the first node whose
+/// id (set via [`mwdg_assign_id`]) equals `id`. If several nodes share the
+/// same id, only the first one in list (registration) order is fed.
+///
+/// # Returns
+/// - `1` if a node with the given id was found and fed.
+/// - `0` otherwise.
+///
+/// # Safety
+/// - `mwdg_init` must have been called.
+/// - All registered `mwdg_node` pointers must still be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_feed_by_id(id: u32) -> i32 {
+    with_critical_section(|state| {
+        let now = state.now();
+        i32::from(state.registry.feed_by_id(id, now))
+    })
+}
+
+/// Feed every registered watchdog at once.
+///
+/// Reads the clock once inside the critical section and resets every
+/// node's timestamp to it. Intended for the main loop after a legitimate
+/// long stall (e.g. a flash write) that kept all tasks from feeding.
+///
+/// # Note
+/// The expired latch is **not** cleared: if [`mwdg_check`] already
+/// detected an expiration it keeps returning `1`. Use
+/// [`mwdg_clear_expired`] for that.
+///
+/// # Safety
+/// - `mwdg_init` must have been called.
+/// - All registered `mwdg_node` pointers must still be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_reset_all() {
+    with_critical_section(|state| {
+        let now = state.now();
+        state.registry.reset_all(now);
+    });
+}
+
 /// Assign a user-chosen identifier to a watchdog node.
 ///
 /// The identifier is stored in the node and can be retrieved later via
@@ -264,6 +627,85 @@ pub unsafe extern "C" fn mwdg_assign_id(wdg: *mut mwdg_node, id: u32) {
     });
 }
 
+/// Read back the identifier assigned to a watchdog node.
+///
+/// Lets C code that caches a `struct mwdg_node *` recover the logical id
+/// set via [`mwdg_assign_id`].
+///
+/// # Returns
+/// - `1` on success; `*out_id` receives the node's id.
+/// - `0` if `wdg` or `out_id` is null (`*out_id` is left untouched).
+///
+/// # Safety
+/// - `wdg` must be either null or a valid pointer to an `mwdg_node`.
+/// - `out_id` must be either null or a valid pointer to a `u32`.
+/// - `mwdg_init` must have been called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_get_id(wdg: *mut mwdg_node, out_id: *mut u32) -> i32 {
+    if out_id.is_null() {
+        return 0;
+    }
+    let Some(pinned) = (unsafe { pin_node_mut(wdg) }) else {
+        return 0;
+    };
+
+    let id = with_critical_section(|_| pinned.id());
+    // SAFETY: `out_id` is non-null and valid for writes (caller contract).
+    unsafe {
+        *out_id = id;
+    }
+
+    1
+}
+
+/// Change the timeout interval of a watchdog node without feeding it.
+///
+/// Unlike calling [`mwdg_add`] again, the node's last-fed timestamp is left
+/// untouched, so a reduced timeout takes effect immediately against the time
+/// of the previous [`mwdg_feed`].
+///
+/// # Parameters
+/// - `wdg`: pointer to a caller-owned [`mwdg_node`].
+/// - `timeout_ms`: the new timeout interval in milliseconds.
+///
+/// # Safety
+/// - `wdg` must be either null or a valid pointer to an `mwdg_node`.
+/// - `mwdg_init` must have been called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_set_timeout(wdg: *mut mwdg_node, timeout_ms: u32) {
+    let Some(pinned) = (unsafe { pin_node_mut(wdg) }) else {
+        return;
+    };
+
+    with_critical_section(|_| {
+        WatchdogRegistry::set_timeout(pinned, timeout_ms);
+    });
+}
+
+/// Set the early-warning interval of a watchdog node.
+///
+/// Once the time since the last feed exceeds `warn_ms` (but not yet the
+/// timeout), [`mwdg_health`] reports a warning. A value of `0` disables the
+/// warning. Can be called before or after [`mwdg_add`].
+///
+/// # Parameters
+/// - `wdg`: pointer to a caller-owned [`mwdg_node`].
+/// - `warn_ms`: the warning interval in milliseconds.
+///
+/// # Safety
+/// - `wdg` must be either null or a valid pointer to an `mwdg_node`.
+/// - `mwdg_init` must have been called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_set_warn_interval(wdg: *mut mwdg_node, warn_ms: u32) {
+    let Some(pinned) = (unsafe { pin_node_mut(wdg) }) else {
+        return;
+    };
+
+    with_critical_section(|_| {
+        WatchdogRegistry::set_warn_interval(pinned, warn_ms);
+    });
+}
+
 /// Check all registered watchdogs for expiration.
 ///
 /// Iterates the linked list of registered watchdogs. For each one,
@@ -280,16 +722,177 @@ pub unsafe extern "C" fn mwdg_assign_id(wdg: *mut mwdg_node, id: u32) {
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn mwdg_check() -> i32 {
     // Fast path: if already expired, skip the critical section entirely.
-    // This is safe because `expired` is only ever set from false to true
-    // (monotonic / latching) inside the critical section, so a stale read
-    // of `true` is always correct.
-    if STATE.as_ref().is_expired() {
+    // `expired` is only ever set from false to true (monotonic / latching)
+    // inside the critical section. The one exception is an explicit
+    // `mwdg_clear_expired`, and a stale read of `true` racing with it only
+    // reports the fault that was just being cleared.
+    if STATE.as_ref().registry.is_expired() {
         return 1;
     }
 
-    with_critical_section(|registry| {
-        let now = unsafe { mwdg_get_time_milliseconds() };
-        i32::from(registry.check(now))
+    with_critical_section(|state| {
+        let now = state.now();
+        i32::from(state.registry.check(now))
+    })
+}
+
+/// Read the latched expired state without scanning.
+///
+/// Unlike [`mwdg_check`], this never walks the list and never enters the
+/// critical section, so it is cheap enough for hot paths such as an idle
+/// hook. The same monotonic-read reasoning as `mwdg_check`'s fast path
+/// applies: the latch only goes from `0` to `1` (until an explicit
+/// [`mwdg_clear_expired`]).
+///
+/// # Returns
+/// - `0` if no expiration has been latched yet.
+/// - `1` if a previous [`mwdg_check`] detected an expiration.
+///
+/// # Safety
+/// - `mwdg_init` must have been called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_is_expired() -> i32 {
+    // See the fast path in `mwdg_check` for why this read needs no lock.
+    i32::from(STATE.as_ref().registry.is_expired())
+}
+
+/// Read the id and detection time of the latched expiration.
+///
+/// Reports the latch recorded by the core registry, so the id is that of
+/// the first node [`mwdg_check`] found expired and the time is the `now`
+/// of that check. Unlike [`mwdg_get_next_expired`] no iteration is needed
+/// for the common single-fault case.
+///
+/// # Parameters
+/// - `out_id`: receives the id of the node that tripped the latch.
+/// - `out_at_ms`: receives the timestamp of the detection.
+///
+/// # Returns
+/// - `1` if the registry is latched (both outputs are written).
+/// - `0` if no expiration has been latched, or if either pointer is null.
+///
+/// # Safety
+/// - `out_id` and `out_at_ms` must be either null or valid pointers to a
+///   `u32`.
+/// - `mwdg_init` must have been called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_get_expired_info(out_id: *mut u32, out_at_ms: *mut u32) -> i32 {
+    if out_id.is_null() || out_at_ms.is_null() {
+        return 0;
+    }
+
+    with_critical_section(|state| {
+        match (
+            state.registry.first_expired_id(),
+            state.registry.expired_at(),
+        ) {
+            (Some(id), Some(at_ms)) => {
+                unsafe {
+                    *out_id = id;
+                    *out_at_ms = at_ms;
+                }
+                1
+            }
+            _ => 0,
+        }
+    })
+}
+
+/// Summarize the state of all registered watchdogs in one call.
+///
+/// Scans the list without latching, so a node in its warning band (see
+/// [`mwdg_set_warn_interval`]) is reported while it can still recover. An
+/// expiration latched by an earlier [`mwdg_check`] is reported as expired
+/// even if the node has been fed since.
+///
+/// # Returns
+/// - `0` if every watchdog is healthy.
+/// - `1` if some watchdog is past its warning interval, none has expired.
+/// - `2` if some watchdog has expired.
+///
+/// # Safety
+/// - `mwdg_init` must have been called.
+/// - All registered `mwdg_node` pointers must still be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_health() -> i32 {
+    with_critical_section(|state| {
+        let now = state.now();
+        let health = if state.registry.is_expired() {
+            Health::Expired
+        } else {
+            state.registry.health(now)
+        };
+
+        match health {
+            Health::Ok => 0,
+            Health::Warning => 1,
+            Health::Expired => 2,
+        }
+    })
+}
+
+/// Clear the latched expired state while keeping all registered watchdogs.
+///
+/// Unlike [`mwdg_init`], the list of registered nodes is left intact, so
+/// monitoring of the other watchdogs resumes after a fault handler has
+/// recovered a stuck task. [`mwdg_check`] scans the list again and
+/// [`mwdg_get_next_expired`] returns `0` until the next expiration latches.
+///
+/// # Warning
+/// This defeats the latching guarantee that makes [`mwdg_check`] safe to
+/// use for gating a hardware watchdog: an expired task that is fed before
+/// the next check is no longer reported. Only call it once the system is
+/// known to have recovered from the fault.
+///
+/// # Safety
+/// - `mwdg_init` must have been called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_clear_expired() {
+    with_critical_section(|state| {
+        state.registry.clear_expired();
+    });
+}
+
+/// Dump the status of every registered watchdog in one call.
+///
+/// Fills `out` with up to `cap` [`mwdg_status`] records in registration
+/// order, evaluated at the current time. Watchdogs beyond `cap` are
+/// skipped. Nothing is latched.
+///
+/// # Usage (C)
+/// ```c
+/// struct mwdg_status status[8];
+/// size_t n = mwdg_snapshot(status, 8);
+/// for (size_t i = 0; i < n; i++) {
+///     printf("%u: %u ms left\n", status[i].id, status[i].remaining_ms);
+/// }
+/// ```
+///
+/// # Returns
+/// The number of records written; `0` if `out` is null.
+///
+/// # Safety
+/// - `out` must be either null or valid for writes of `cap` consecutive
+///   `mwdg_status` records.
+/// - `mwdg_init` must have been called.
+/// - All registered `mwdg_node` pointers must still be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_snapshot(out: *mut mwdg_status, cap: usize) -> usize {
+    if out.is_null() {
+        return 0;
+    }
+
+    // SAFETY: the caller guarantees `out` is valid for `cap` writes. The
+    // records are zeroed first (all-zero is a valid `mwdg_status`), so the
+    // slice never covers uninitialized memory.
+    let out = unsafe {
+        ptr::write_bytes(out, 0, cap);
+        core::slice::from_raw_parts_mut(out, cap)
+    };
+
+    with_critical_section(|state| {
+        let now = state.now();
+        state.registry.snapshot(now, out)
     })
 }
 
@@ -340,6 +943,12 @@ pub unsafe extern "C" fn mwdg_check() -> i32 {
 /// In typical RTOS usage the check loop runs from a single supervisory task,
 /// so this is not a concern.
 ///
+/// Resuming from the cursor this shim handed out last is O(1). Any other
+/// non-null cursor, or one resumed after a node was removed, is first
+/// validated against the list inside the critical section, which is O(n)
+/// per call and O(n²) for a full loop. Interleaving two iterations (e.g.
+/// with [`mwdg_get_next_node`]) therefore costs a list walk per call.
+///
 /// # Safety
 /// - `cursor` must be either null or a valid pointer to a `*mut mwdg_node`.
 /// - `out_id` must be either null or a valid pointer to a `u32`.
@@ -354,22 +963,163 @@ pub unsafe extern "C" fn mwdg_get_next_expired(
         return 0;
     }
 
-    with_critical_section(|registry| {
-        // Convert the C cursor (*mut *mut mwdg_node) to our internal cursor
-        // (*const WatchdogNode).
-        let mut internal_cursor: *const WatchdogNode = if unsafe { (*cursor).is_null() } {
-            ptr::null()
-        } else {
-            unsafe { cast_node(*cursor).cast_const() }
-        };
+    with_critical_section(|state| {
+        // Convert the C cursor (*mut *mut mwdg_node) to our internal cursor;
+        // the registry validates it before following it.
+        let mut internal_cursor = state.resume_cursor(unsafe { *cursor });
 
-        match registry.next_expired(&mut internal_cursor) {
+        match state
+            .registry
+            .next_expired_node(&mut internal_cursor)
+            .map(|node| node.id())
+        {
             Some(id) => {
+                state.cursor = internal_cursor;
                 unsafe {
                     *out_id = id;
                     // Cast back: internal_cursor points to a WatchdogNode
                     // which is layout-compatible with mwdg_node.
-                    *cursor = internal_cursor.cast_mut().cast::<mwdg_node>();
+                    *cursor = internal_cursor.into_raw().cast_mut().cast::<mwdg_node>();
+                }
+                1
+            }
+            None => 0,
+        }
+    })
+}
+
+/// Get the next watchdog in its warning band.
+///
+/// Iterates the nodes whose time since the last feed exceeds their warning
+/// interval (see [`mwdg_set_warn_interval`]) but not yet their timeout,
+/// evaluated against the current clock. Unlike [`mwdg_get_next_expired`] no
+/// prior [`mwdg_check`] is required and nothing is latched. Cursor and
+/// null-pointer handling are identical to `mwdg_get_next_expired`.
+///
+/// # Usage (C)
+/// ```c
+/// struct mwdg_node *cursor = NULL;
+/// uint32_t id;
+/// while (mwdg_get_next_warning(&cursor, &id)) {
+///     printf("watchdog id %u is late\n", id);
+/// }
+/// ```
+///
+/// # Parameters
+/// - `cursor`: iteration state, see [`mwdg_get_next_expired`].
+/// - `out_id`: pointer to a `u32` receiving the node's identifier.
+///
+/// # Returns
+/// - `1` if a node in its warning band was found (`*out_id` is written,
+///   `*cursor` is advanced).
+/// - `0` when no more such nodes remain, or if `cursor` or `out_id` is
+///   null.
+///
+/// # Note
+/// Resuming a cursor costs the same as in [`mwdg_get_next_expired`].
+///
+/// # Safety
+/// - `cursor` must be either null or a valid pointer to a `*mut mwdg_node`.
+/// - `out_id` must be either null or a valid pointer to a `u32`.
+/// - `mwdg_init` must have been called.
+/// - All registered `mwdg_node` pointers must still be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_get_next_warning(
+    cursor: *mut *mut mwdg_node,
+    out_id: *mut u32,
+) -> i32 {
+    if cursor.is_null() || out_id.is_null() {
+        return 0;
+    }
+
+    with_critical_section(|state| {
+        let now = state.now();
+        let mut internal_cursor = state.resume_cursor(unsafe { *cursor });
+
+        match state.registry.next_warning(&mut internal_cursor, now) {
+            Some(id) => {
+                state.cursor = internal_cursor;
+                unsafe {
+                    *out_id = id;
+                    *cursor = internal_cursor.into_raw().cast_mut().cast::<mwdg_node>();
+                }
+                1
+            }
+            None => 0,
+        }
+    })
+}
+
+/// Get the time remaining before a watchdog expires.
+///
+/// Computes, against the current clock, how many milliseconds the node may
+/// still go without being fed. Useful for surfacing per-task health in a
+/// diagnostic shell.
+///
+/// # Returns
+/// - The remaining time in milliseconds.
+/// - `0` if the node is already past its timeout or if `wdg` is null.
+///
+/// # Safety
+/// - `wdg` must be either null or a valid pointer to an `mwdg_node`.
+/// - `mwdg_init` must have been called.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_get_time_to_expiry(wdg: *mut mwdg_node) -> u32 {
+    let Some(pinned) = (unsafe { pin_node_mut(wdg) }) else {
+        return 0;
+    };
+
+    with_critical_section(|state| WatchdogRegistry::time_to_expiry(pinned.as_ref(), state.now()))
+}
+
+/// Iterate over all registered watchdogs, regardless of their health.
+///
+/// Same cursor protocol as [`mwdg_get_next_expired`], but every registered
+/// node is yielded in list order and no prior [`mwdg_check`] is needed.
+/// Useful for a "show all tasks" diagnostic command.
+///
+/// # Usage (C)
+/// ```c
+/// struct mwdg_node *cursor = NULL;
+/// uint32_t id;
+/// while (mwdg_get_next_node(&cursor, &id)) {
+///     printf("registered watchdog id: %u\n", id);
+/// }
+/// ```
+///
+/// # Returns
+/// - `1` if a node was found (`*out_id` is written, `*cursor` is advanced).
+/// - `0` at the end of the list, or if `cursor` or `out_id` is null.
+///
+/// # Note
+/// Each call enters and exits the critical section independently, and a
+/// cursor that was not handed out last is validated by walking the list,
+/// see [`mwdg_get_next_expired`].
+///
+/// # Safety
+/// - `cursor` must be either null or a valid pointer to a `*mut mwdg_node`.
+/// - `out_id` must be either null or a valid pointer to a `u32`.
+/// - `mwdg_init` must have been called.
+/// - All registered `mwdg_node` pointers must still be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mwdg_get_next_node(cursor: *mut *mut mwdg_node, out_id: *mut u32) -> i32 {
+    if cursor.is_null() || out_id.is_null() {
+        return 0;
+    }
+
+    with_critical_section(|state| {
+        let mut internal_cursor = state.resume_cursor(unsafe { *cursor });
+
+        match state
+            .registry
+            .next_node(&mut internal_cursor)
+            .map(|node| node.id())
+        {
+            Some(id) => {
+                state.cursor = internal_cursor;
+                unsafe {
+                    *out_id = id;
+                    *cursor = internal_cursor.into_raw().cast_mut().cast::<mwdg_node>();
                 }
                 1
             }
@@ -1,3 +1,6 @@
+mod common;
+
+use common::{advance_mock_time, set_mock_time};
 use mwdg_ffi::*;
 
 use core::ptr;
@@ -16,43 +19,9 @@ fn safe_mwdg_add(wdg: *mut mwdg_node, timeout_ms: u32) {
     }
 }
 
-static MOCK_TIME: AtomicU32 = AtomicU32::new(0);
-
-extern "C" fn mock_get_time_ms() -> u32 {
-    MOCK_TIME.load(Ordering::Relaxed)
-}
-
-extern "C" fn mock_enter_critical() {
-    // no-op for single-threaded tests
-}
-
-extern "C" fn mock_exit_critical() {
-    // no-op for single-threaded tests
-}
-
-/// User-provided function that returns the current time in milliseconds.
-#[unsafe(no_mangle)]
-pub extern "C" fn mwdg_get_time_milliseconds() -> u32 {
-    mock_get_time_ms()
-}
-/// User-provided function to enter a critical section.
-#[unsafe(no_mangle)]
-pub extern "C" fn mwdg_enter_critical() {
-    mock_enter_critical();
-}
-/// User-provided function to exit a critical section.
-#[unsafe(no_mangle)]
-pub extern "C" fn mwdg_exit_critical() {
-    mock_exit_critical();
-}
-
-fn set_time(ms: u32) {
-    MOCK_TIME.store(ms, Ordering::Relaxed);
-}
-
 /// Reset global state between tests (since tests share the static).
 fn reset() {
-    set_time(0);
+    set_mock_time(0);
     safe_mwdg_init();
 }
 
@@ -85,7 +54,7 @@ fn test_check_add_with_remove() {
     let mut wdg = new_wdg();
 
     safe_mwdg_add(&mut wdg, 100);
-    set_time(200);
+    set_mock_time(200);
     unsafe {
         mwdg_remove(&mut wdg);
     }
@@ -107,7 +76,7 @@ fn test_check_add_multiple_with_remove() {
     safe_mwdg_add(&mut wdg1, 100);
     safe_mwdg_add(&mut wdg2, 300);
     safe_mwdg_add(&mut wdg3, 199);
-    set_time(200);
+    set_mock_time(200);
     unsafe {
         mwdg_remove(&mut wdg1);
         mwdg_remove(&mut wdg3);
@@ -133,7 +102,7 @@ fn test_check_add_with_remove_and_add_again() {
     safe_mwdg_add(&mut wdg1, 100);
     safe_mwdg_add(&mut wdg2, 300);
     safe_mwdg_add(&mut wdg3, 199);
-    set_time(200);
+    set_mock_time(200);
     unsafe {
         mwdg_remove(&mut wdg1);
         mwdg_remove(&mut wdg3);
@@ -149,7 +118,7 @@ fn test_check_add_with_remove_and_add_again() {
         mwdg_add(&mut wdg4, 400);
         mwdg_remove(&mut wdg2);
     }
-    set_time(350);
+    set_mock_time(350);
 
     assert_eq!(
         unsafe { mwdg_check() },
@@ -167,10 +136,123 @@ fn test_check_remove_null() {
     assert_eq!(unsafe { mwdg_check() }, 0, "Empty list should be healthy");
 }
 
+#[test]
+fn test_remove_ptr_via_void_handle() {
+    reset();
+    set_mock_time(0);
+    let mut wdg = new_wdg();
+    safe_mwdg_add(&mut wdg, 100);
+
+    let handle: *mut core::ffi::c_void = (&raw mut wdg).cast();
+    assert_eq!(unsafe { mwdg_remove_ptr(handle) }, 1);
+    assert_eq!(unsafe { mwdg_count() }, 0);
+
+    // Already removed.
+    assert_eq!(unsafe { mwdg_remove_ptr(handle) }, 0);
+
+    set_mock_time(1000);
+    assert_eq!(unsafe { mwdg_check() }, 0);
+}
+
+#[test]
+fn test_remove_head_middle_tail() {
+    reset();
+    set_mock_time(0);
+    let mut wdgs = [new_wdg(), new_wdg(), new_wdg(), new_wdg()];
+    for wdg in &mut wdgs {
+        safe_mwdg_add(wdg, 100);
+    }
+
+    unsafe {
+        mwdg_remove(&mut wdgs[1]);
+        mwdg_remove(&mut wdgs[0]);
+        mwdg_remove(&mut wdgs[3]);
+    }
+    assert_eq!(unsafe { mwdg_count() }, 1);
+
+    // The survivor is still monitored and the others can be re-added.
+    safe_mwdg_add(&mut wdgs[0], 300);
+    assert_eq!(unsafe { mwdg_count() }, 2);
+    set_mock_time(150);
+    assert_eq!(unsafe { mwdg_check() }, 1);
+}
+
+#[test]
+fn test_node_layout_matches_core() {
+    assert_eq!(
+        core::mem::size_of::<mwdg_node>(),
+        core::mem::size_of::<mwdg::WatchdogNode>()
+    );
+    assert_eq!(
+        core::mem::align_of::<mwdg_node>(),
+        core::mem::align_of::<mwdg::WatchdogNode>()
+    );
+}
+
+#[test]
+fn test_remove_ptr_null() {
+    reset();
+    assert_eq!(unsafe { mwdg_remove_ptr(ptr::null_mut()) }, 0);
+}
+
+#[test]
+fn test_remove_all() {
+    reset();
+
+    let mut wdg1 = new_wdg();
+    let mut wdg2 = new_wdg();
+    let mut wdg3 = new_wdg();
+
+    safe_mwdg_add(&mut wdg1, 100);
+    safe_mwdg_add(&mut wdg2, 200);
+    safe_mwdg_add(&mut wdg3, 300);
+    unsafe {
+        mwdg_remove_all();
+    }
+
+    set_mock_time(1000);
+    assert_eq!(
+        unsafe { mwdg_check() },
+        0,
+        "No watchdogs should remain registered"
+    );
+
+    // The nodes can be re-added after the bulk removal.
+    safe_mwdg_add(&mut wdg1, 100);
+    safe_mwdg_add(&mut wdg2, 200);
+    assert_eq!(unsafe { mwdg_check() }, 0);
+    set_mock_time(1150);
+    assert_eq!(
+        unsafe { mwdg_check() },
+        1,
+        "Re-added wdg1 should expire normally"
+    );
+}
+
+#[test]
+fn test_count() {
+    reset();
+    assert_eq!(unsafe { mwdg_count() }, 0);
+
+    let mut wdg1 = new_wdg();
+    let mut wdg2 = new_wdg();
+    let mut wdg3 = new_wdg();
+
+    safe_mwdg_add(&mut wdg1, 100);
+    safe_mwdg_add(&mut wdg2, 200);
+    safe_mwdg_add(&mut wdg3, 300);
+    assert_eq!(unsafe { mwdg_count() }, 3);
+
+    unsafe {
+        mwdg_remove(&mut wdg2);
+    }
+    assert_eq!(unsafe { mwdg_count() }, 2, "Two watchdogs should remain");
+}
+
 #[test]
 fn test_register_single_and_check_ok() {
     reset();
-    set_time(1000);
+    set_mock_time(1000);
     let mut wdg = new_wdg();
     safe_mwdg_add(&mut wdg, 100);
     // Still at time 1000, no time has elapsed
@@ -180,26 +262,26 @@ fn test_register_single_and_check_ok() {
 #[test]
 fn test_single_expired() {
     reset();
-    set_time(1000);
+    set_mock_time(1000);
     let mut wdg = new_wdg();
     safe_mwdg_add(&mut wdg, 100);
-    set_time(1150);
+    set_mock_time(1150);
     assert_eq!(unsafe { mwdg_check() }, 1, "Should detect expired watchdog");
 }
 
 #[test]
 fn test_feed_resets_timer() {
     reset();
-    set_time(1000);
+    set_mock_time(1000);
     let mut wdg = new_wdg();
     safe_mwdg_add(&mut wdg, 100);
     // Advance 80ms and feed
-    set_time(1080);
+    advance_mock_time(80);
     unsafe {
         mwdg_feed(&mut wdg);
     }
     // Advance another 80ms (total 160ms from register, but only 80ms from last feed)
-    set_time(1160);
+    advance_mock_time(80);
     assert_eq!(
         unsafe { mwdg_check() },
         0,
@@ -210,7 +292,7 @@ fn test_feed_resets_timer() {
 #[test]
 fn test_multiple_all_ok() {
     reset();
-    set_time(500);
+    set_mock_time(500);
     let mut wdg1 = new_wdg();
     let mut wdg2 = new_wdg();
     let mut wdg3 = new_wdg();
@@ -225,7 +307,7 @@ fn test_multiple_all_ok() {
 #[test]
 fn test_multiple_one_expired() {
     reset();
-    set_time(500);
+    set_mock_time(500);
     let mut wdg1 = new_wdg();
     let mut wdg2 = new_wdg();
     let mut wdg3 = new_wdg();
@@ -234,7 +316,7 @@ fn test_multiple_one_expired() {
     safe_mwdg_add(&mut wdg2, 200);
     safe_mwdg_add(&mut wdg3, 300);
 
-    set_time(650);
+    set_mock_time(650);
     assert_eq!(unsafe { mwdg_check() }, 1, "wdg1 should be expired");
 }
 
@@ -243,13 +325,13 @@ fn test_wrapping_no_expire() {
     reset();
     // Set time near u32::MAX
     let near_max = u32::MAX - 50;
-    set_time(near_max);
+    set_mock_time(near_max);
     let mut wdg = new_wdg();
 
     safe_mwdg_add(&mut wdg, 100);
 
     // Wrap around: 30ms past u32::MAX (i.e., elapsed = 80ms < 100ms)
-    set_time(near_max.wrapping_add(80));
+    set_mock_time(near_max.wrapping_add(80));
     assert_eq!(
         unsafe { mwdg_check() },
         0,
@@ -262,13 +344,13 @@ fn test_wrapping_expired() {
     reset();
     // Set time near u32::MAX
     let near_max = u32::MAX - 50;
-    set_time(near_max);
+    set_mock_time(near_max);
     let mut wdg = new_wdg();
 
     safe_mwdg_add(&mut wdg, 100);
 
     // Wrap around: 150ms elapsed (past 100ms timeout)
-    set_time(near_max.wrapping_add(150));
+    set_mock_time(near_max.wrapping_add(150));
     assert_eq!(
         unsafe { mwdg_check() },
         1,
@@ -283,12 +365,12 @@ fn test_once_expired_always_expired() {
     let mut wdg1 = new_wdg();
     let mut wdg2 = new_wdg();
 
-    set_time(0);
+    set_mock_time(0);
     unsafe {
         mwdg_add(&mut wdg1, 1);
         mwdg_add(&mut wdg2, 5);
     }
-    set_time(2);
+    set_mock_time(2);
 
     assert_eq!(1, unsafe { mwdg_check() }, "WDG1 should be already expired");
     unsafe {
@@ -301,18 +383,52 @@ fn test_once_expired_always_expired() {
     );
 }
 
+#[test]
+fn test_clear_expired_resumes_monitoring() {
+    reset();
+
+    let mut wdg1 = new_wdg();
+    let mut wdg2 = new_wdg();
+
+    set_mock_time(0);
+    safe_mwdg_add(&mut wdg1, 100);
+    safe_mwdg_add(&mut wdg2, 500);
+
+    set_mock_time(150);
+    assert_eq!(unsafe { mwdg_check() }, 1, "wdg1 should be expired");
+
+    // The fault handler recovers the stuck task and clears the latch.
+    unsafe {
+        mwdg_feed(&mut wdg1);
+        mwdg_clear_expired();
+    }
+    assert_eq!(
+        unsafe { mwdg_check() },
+        0,
+        "Healthy check must return 0 after clearing the latch"
+    );
+
+    let mut cursor: *mut mwdg_node = ptr::null_mut();
+    let mut id = 0;
+    assert_eq!(unsafe { mwdg_get_next_expired(&mut cursor, &mut id) }, 0);
+
+    // Remaining watchdogs are still monitored.
+    set_mock_time(501);
+    assert_eq!(unsafe { mwdg_check() }, 1, "wdg2 should expire");
+}
+
 #[test]
 fn test_multiple_add_of_the_same_node() {
     reset();
 
     let mut wdg = new_wdg();
 
-    set_time(0);
+    set_mock_time(0);
     unsafe {
         mwdg_add(&mut wdg, 1);
-        set_time(2);
+        set_mock_time(2);
         mwdg_add(&mut wdg, 1);
-        set_time(4);
+        set_mock_time(4);
         mwdg_add(&mut wdg, 1);
     }
 
@@ -322,7 +438,7 @@ fn test_multiple_add_of_the_same_node() {
 #[test]
 fn test_assign_id_before_add() {
     reset();
-    set_time(0);
+    set_mock_time(0);
     let mut wdg = new_wdg();
     unsafe {
         mwdg_assign_id(&mut wdg, 42);
@@ -335,7 +451,7 @@ fn test_assign_id_before_add() {
 #[test]
 fn test_assign_id_after_add() {
     reset();
-    set_time(0);
+    set_mock_time(0);
     let mut wdg = new_wdg();
     unsafe {
         mwdg_add(&mut wdg, 100);
@@ -354,6 +470,334 @@ fn test_assign_id_null_safe() {
     assert_eq!(unsafe { mwdg_check() }, 0);
 }
 
+#[test]
+fn test_set_timeout_reduced_expires_against_old_timestamp() {
+    reset();
+    set_mock_time(1000);
+    let mut wdg = new_wdg();
+    safe_mwdg_add(&mut wdg, 500);
+
+    set_mock_time(1200);
+    assert_eq!(unsafe { mwdg_check() }, 0);
+
+    // 200 ms have already elapsed since the last feed at 1000.
+    unsafe {
+        mwdg_set_timeout(&mut wdg, 100);
+    }
+    assert_eq!(unsafe { mwdg_check() }, 1, "Reduced timeout must expire");
+}
+
+#[test]
+fn test_set_timeout_null_safe() {
+    reset();
+    unsafe {
+        mwdg_set_timeout(ptr::null_mut(), 100);
+    }
+    assert_eq!(unsafe { mwdg_check() }, 0);
+}
+
+#[test]
+fn test_is_expired_reads_latch() {
+    reset();
+    set_mock_time(0);
+    let mut wdg = new_wdg();
+    safe_mwdg_add(&mut wdg, 100);
+
+    set_mock_time(150);
+    // Not scanned yet: the latch is still clear.
+    assert_eq!(unsafe { mwdg_is_expired() }, 0);
+
+    assert_eq!(unsafe { mwdg_check() }, 1);
+    assert_eq!(unsafe { mwdg_is_expired() }, 1);
+}
+
+#[test]
+fn test_expired_info_follows_latch() {
+    reset();
+    set_mock_time(0);
+    let mut wdg1 = new_wdg();
+    let mut wdg2 = new_wdg();
+    safe_mwdg_add(&mut wdg1, 100);
+    safe_mwdg_add(&mut wdg2, 50);
+    unsafe {
+        mwdg_assign_id(&mut wdg1, 1);
+        mwdg_assign_id(&mut wdg2, 2);
+    }
+
+    let (mut id, mut at_ms) = (0, 0);
+    assert_eq!(unsafe { mwdg_get_expired_info(&mut id, &mut at_ms) }, 0);
+
+    set_mock_time(60);
+    assert_eq!(unsafe { mwdg_check() }, 1);
+    assert_eq!(unsafe { mwdg_get_expired_info(&mut id, &mut at_ms) }, 1);
+    assert_eq!((id, at_ms), (2, 60));
+
+    // Later checks keep the original detection.
+    set_mock_time(200);
+    assert_eq!(unsafe { mwdg_check() }, 1);
+    assert_eq!(unsafe { mwdg_get_expired_info(&mut id, &mut at_ms) }, 1);
+    assert_eq!((id, at_ms), (2, 60));
+
+    // Clearing drops the latch; the next check records the new cause.
+    unsafe {
+        mwdg_clear_expired();
+        mwdg_remove(&mut wdg2);
+    }
+    assert_eq!(unsafe { mwdg_is_expired() }, 0);
+    assert_eq!(unsafe { mwdg_get_expired_info(&mut id, &mut at_ms) }, 0);
+    assert_eq!(unsafe { mwdg_check() }, 1);
+    assert_eq!(unsafe { mwdg_get_expired_info(&mut id, &mut at_ms) }, 1);
+    assert_eq!((id, at_ms), (1, 200));
+
+    assert_eq!(
+        unsafe { mwdg_get_expired_info(ptr::null_mut(), &mut at_ms) },
+        0
+    );
+}
+
+#[test]
+fn test_health_codes() {
+    reset();
+    set_mock_time(0);
+    let mut wdg = new_wdg();
+    safe_mwdg_add(&mut wdg, 100);
+    unsafe { mwdg_set_warn_interval(&mut wdg, 50) };
+
+    set_mock_time(50);
+    assert_eq!(unsafe { mwdg_health() }, 0);
+
+    set_mock_time(80);
+    assert_eq!(unsafe { mwdg_health() }, 1);
+    // A warning does not latch.
+    assert_eq!(unsafe { mwdg_is_expired() }, 0);
+    unsafe { mwdg_feed(&mut wdg) };
+    assert_eq!(unsafe { mwdg_health() }, 0);
+
+    set_mock_time(181);
+    assert_eq!(unsafe { mwdg_health() }, 2);
+    assert_eq!(unsafe { mwdg_is_expired() }, 0);
+}
+
+#[test]
+fn test_health_reports_latched_expiry() {
+    reset();
+    set_mock_time(0);
+    let mut wdg = new_wdg();
+    safe_mwdg_add(&mut wdg, 100);
+
+    set_mock_time(150);
+    assert_eq!(unsafe { mwdg_check() }, 1);
+    unsafe { mwdg_feed(&mut wdg) };
+    assert_eq!(unsafe { mwdg_health() }, 2);
+}
+
+#[test]
+fn test_set_warn_interval_null_safe() {
+    reset();
+    unsafe {
+        mwdg_set_warn_interval(ptr::null_mut(), 50);
+    }
+    // No crash is the assertion
+}
+
+static EXPIRED_CALLBACK_ID: AtomicU32 = AtomicU32::new(0);
+
+extern "C" fn on_expiry(id: u32) {
+    EXPIRED_CALLBACK_ID.store(id, Ordering::Relaxed);
+}
+
+#[test]
+fn test_expiry_callback_receives_id() {
+    reset();
+    set_mock_time(0);
+    let mut wdg1 = new_wdg();
+    let mut wdg2 = new_wdg();
+    unsafe {
+        mwdg_assign_id(&mut wdg1, 1);
+        mwdg_assign_id(&mut wdg2, 42);
+        mwdg_set_expiry_callback(Some(on_expiry));
+    }
+    safe_mwdg_add(&mut wdg1, 500);
+    safe_mwdg_add(&mut wdg2, 100);
+
+    set_mock_time(100);
+    assert_eq!(unsafe { mwdg_check() }, 0);
+    assert_eq!(EXPIRED_CALLBACK_ID.load(Ordering::Relaxed), 0);
+
+    set_mock_time(150);
+    assert_eq!(unsafe { mwdg_check() }, 1);
+    assert_eq!(EXPIRED_CALLBACK_ID.load(Ordering::Relaxed), 42);
+}
+
+#[test]
+fn test_expiry_callback_null_resets() {
+    reset();
+    set_mock_time(0);
+    let mut wdg = new_wdg();
+    unsafe {
+        mwdg_set_expiry_callback(None);
+    }
+    safe_mwdg_add(&mut wdg, 100);
+
+    set_mock_time(150);
+    assert_eq!(unsafe { mwdg_check() }, 1);
+}
+
+#[test]
+fn test_snapshot_two_nodes() {
+    reset();
+    set_mock_time(0);
+    let mut wdg1 = new_wdg();
+    let mut wdg2 = new_wdg();
+    unsafe {
+        mwdg_assign_id(&mut wdg1, 1);
+        mwdg_assign_id(&mut wdg2, 2);
+    }
+    safe_mwdg_add(&mut wdg1, 100);
+    safe_mwdg_add(&mut wdg2, 200);
+
+    set_mock_time(150);
+    let mut out = [mwdg_status::default(); 4];
+    let written = unsafe { mwdg_snapshot(out.as_mut_ptr(), out.len()) };
+
+    assert_eq!(written, 2);
+    assert_eq!(
+        (
+            out[0].id,
+            out[0].timeout_ms,
+            out[0].remaining_ms,
+            out[0].expired
+        ),
+        (1, 100, 0, 1)
+    );
+    assert_eq!(
+        (
+            out[1].id,
+            out[1].timeout_ms,
+            out[1].remaining_ms,
+            out[1].expired
+        ),
+        (2, 200, 50, 0)
+    );
+    assert_eq!(out[2].id, 0);
+    assert_eq!(unsafe { mwdg_is_expired() }, 0, "snapshot must not latch");
+}
+
+#[test]
+fn test_snapshot_null_out() {
+    reset();
+    let mut wdg = new_wdg();
+    safe_mwdg_add(&mut wdg, 100);
+    assert_eq!(unsafe { mwdg_snapshot(ptr::null_mut(), 4) }, 0);
+}
+
+#[test]
+fn test_feed_at_uses_explicit_timestamp() {
+    reset();
+    set_mock_time(0);
+    let mut wdg = new_wdg();
+    safe_mwdg_add(&mut wdg, 100);
+
+    // The clock still reads 0; the feed is recorded at 500.
+    unsafe {
+        mwdg_feed_at(&mut wdg, 500);
+    }
+
+    set_mock_time(600);
+    assert_eq!(unsafe { mwdg_check() }, 0, "Fed at 500, 100 ms elapsed");
+    set_mock_time(601);
+    assert_eq!(unsafe { mwdg_check() }, 1);
+}
+
+#[test]
+fn test_feed_at_null_safe() {
+    reset();
+    unsafe {
+        mwdg_feed_at(ptr::null_mut(), 10);
+    }
+    assert_eq!(unsafe { mwdg_check() }, 0);
+}
+
+#[test]
+fn test_reset_all_feeds_every_node() {
+    reset();
+    set_mock_time(0);
+    let mut wdg1 = new_wdg();
+    let mut wdg2 = new_wdg();
+    safe_mwdg_add(&mut wdg1, 100);
+    safe_mwdg_add(&mut wdg2, 200);
+
+    // A long stall: both nodes are overdue, but nothing checked yet.
+    set_mock_time(1000);
+    unsafe {
+        mwdg_reset_all();
+    }
+
+    set_mock_time(1050);
+    assert_eq!(unsafe { mwdg_check() }, 0);
+}
+
+#[test]
+fn test_reset_all_keeps_latch() {
+    reset();
+    set_mock_time(0);
+    let mut wdg = new_wdg();
+    safe_mwdg_add(&mut wdg, 100);
+
+    set_mock_time(200);
+    assert_eq!(unsafe { mwdg_check() }, 1);
+    unsafe {
+        mwdg_reset_all();
+    }
+    assert_eq!(unsafe { mwdg_check() }, 1, "Latch must survive reset_all");
+}
+
+#[test]
+fn test_get_id_reads_back_assigned_id() {
+    reset();
+    let mut wdg = new_wdg();
+    let mut id: u32 = 0;
+
+    unsafe {
+        mwdg_assign_id(&mut wdg, 77);
+    }
+    assert_eq!(unsafe { mwdg_get_id(&mut wdg, &mut id) }, 1);
+    assert_eq!(id, 77);
+}
+
+#[test]
+fn test_get_id_null_args() {
+    reset();
+    let mut wdg = new_wdg();
+    let mut id: u32 = 5;
+
+    assert_eq!(unsafe { mwdg_get_id(ptr::null_mut(), &mut id) }, 0);
+    assert_eq!(unsafe { mwdg_get_id(&mut wdg, ptr::null_mut()) }, 0);
+    assert_eq!(id, 5);
+}
+
+#[test]
+fn test_feed_by_id() {
+    reset();
+    let mut wdg1 = new_wdg();
+    let mut wdg2 = new_wdg();
+
+    set_mock_time(0);
+    unsafe {
+        mwdg_assign_id(&mut wdg1, 1);
+        mwdg_assign_id(&mut wdg2, 2);
+    }
+    safe_mwdg_add(&mut wdg1, 100);
+    safe_mwdg_add(&mut wdg2, 500);
+
+    set_mock_time(80);
+    assert_eq!(unsafe { mwdg_feed_by_id(1) }, 1);
+    assert_eq!(unsafe { mwdg_feed_by_id(3) }, 0, "Unknown id is not fed");
+
+    set_mock_time(150);
+    assert_eq!(unsafe { mwdg_check() }, 0, "wdg1 fed by id must not expire");
+}
+
 /// Helper: collect all expired IDs by iterating with mwdg_get_next_expired.
 fn collect_expired_ids() -> Vec<u32> {
     let mut ids = Vec::new();
@@ -375,7 +819,7 @@ fn test_get_next_expired_empty_list() {
 #[test]
 fn test_get_next_expired_none_expired() {
     reset();
-    set_time(0);
+    set_mock_time(0);
     let mut wdg1 = new_wdg();
     let mut wdg2 = new_wdg();
     unsafe {
@@ -393,7 +837,7 @@ fn test_get_next_expired_none_expired() {
 #[test]
 fn test_get_next_expired_one_expired() {
     reset();
-    set_time(0);
+    set_mock_time(0);
     let mut wdg1 = new_wdg();
     let mut wdg2 = new_wdg();
     let mut wdg3 = new_wdg();
@@ -407,7 +851,7 @@ fn test_get_next_expired_one_expired() {
         mwdg_add(&mut wdg3, 300);
     }
 
-    set_time(150);
+    set_mock_time(150);
     // wdg1 (100ms) expired, wdg2 (200ms) and wdg3 (300ms) ok
     assert_eq!(
         unsafe { mwdg_check() },
@@ -422,7 +866,7 @@ fn test_get_next_expired_one_expired() {
 #[test]
 fn test_get_next_expired_multiple_expired() {
     reset();
-    set_time(0);
+    set_mock_time(0);
     let mut wdg1 = new_wdg();
     let mut wdg2 = new_wdg();
     let mut wdg3 = new_wdg();
@@ -435,7 +879,7 @@ fn test_get_next_expired_multiple_expired() {
         mwdg_add(&mut wdg3, 300);
     }
 
-    set_time(250);
+    set_mock_time(250);
     // wdg1 (100ms) and wdg2 (200ms) expired, wdg3 (300ms) ok
     assert_eq!(
         unsafe { mwdg_check() },
@@ -444,7 +888,7 @@ fn test_get_next_expired_multiple_expired() {
     );
     let ids = collect_expired_ids();
     assert_eq!(ids.len(), 2, "Two nodes should be expired");
-    // Order depends on list order (head-prepend: wdg3 -> wdg2 -> wdg1)
+    // Registration order: wdg1 -> wdg2 -> wdg3
     assert!(ids.contains(&10), "wdg1 (id=10) should be expired");
     assert!(ids.contains(&20), "wdg2 (id=20) should be expired");
 }
@@ -452,7 +896,7 @@ fn test_get_next_expired_multiple_expired() {
 #[test]
 fn test_get_next_expired_all_expired() {
     reset();
-    set_time(0);
+    set_mock_time(0);
     let mut wdg1 = new_wdg();
     let mut wdg2 = new_wdg();
     let mut wdg3 = new_wdg();
@@ -466,7 +910,7 @@ fn test_get_next_expired_all_expired() {
         mwdg_add(&mut wdg3, 70);
     }
 
-    set_time(100); // All expired
+    set_mock_time(100); // All expired
     assert_eq!(
         unsafe { mwdg_check() },
         1,
@@ -482,14 +926,14 @@ fn test_get_next_expired_all_expired() {
 #[test]
 fn test_get_next_expired_default_id_zero() {
     reset();
-    set_time(0);
+    set_mock_time(0);
     let mut wdg = new_wdg();
     // Do NOT assign an id — it should default to 0
     unsafe {
         mwdg_add(&mut wdg, 50);
     }
 
-    set_time(100);
+    set_mock_time(100);
     assert_eq!(
         unsafe { mwdg_check() },
         1,
@@ -526,7 +970,7 @@ fn test_get_next_expired_both_null() {
 #[test]
 fn test_get_next_expired_after_feed() {
     reset();
-    set_time(0);
+    set_mock_time(0);
     let mut wdg1 = new_wdg();
     let mut wdg2 = new_wdg();
     unsafe {
@@ -536,11 +980,11 @@ fn test_get_next_expired_after_feed() {
         mwdg_add(&mut wdg2, 100);
     }
 
-    set_time(80);
+    set_mock_time(80);
     unsafe {
         mwdg_feed(&mut wdg1);
     } // reset wdg1 timer to 80
-    set_time(150); // wdg1: elapsed=70 < 100 (ok), wdg2: elapsed=150 > 100 (expired)
+    set_mock_time(150); // wdg1: elapsed=70 < 100 (ok), wdg2: elapsed=150 > 100 (expired)
 
     assert_eq!(
         unsafe { mwdg_check() },
@@ -556,7 +1000,7 @@ fn test_get_next_expired_after_feed() {
 fn test_get_next_expired_wrapping_time() {
     reset();
     let near_max = u32::MAX - 50;
-    set_time(near_max);
+    set_mock_time(near_max);
     let mut wdg = new_wdg();
     unsafe {
         mwdg_assign_id(&mut wdg, 77);
@@ -564,7 +1008,7 @@ fn test_get_next_expired_wrapping_time() {
     }
 
     // Wrap around: 150ms elapsed (past 100ms timeout)
-    set_time(near_max.wrapping_add(150));
+    set_mock_time(near_max.wrapping_add(150));
     assert_eq!(
         unsafe { mwdg_check() },
         1,
@@ -578,7 +1022,7 @@ fn test_get_next_expired_wrapping_time() {
 #[test]
 fn test_get_next_expired_without_prior_check() {
     reset();
-    set_time(0);
+    set_mock_time(0);
     let mut wdg = new_wdg();
     unsafe {
         mwdg_assign_id(&mut wdg, 5);
@@ -586,7 +1030,7 @@ fn test_get_next_expired_without_prior_check() {
     }
 
     // Advance time past timeout but do NOT call mwdg_check
-    set_time(100);
+    set_mock_time(100);
 
     // mwdg_get_next_expired should return 0 because mwdg_check has not
     // set state.expired = true.
@@ -605,7 +1049,7 @@ fn test_get_next_expired_after_feed_race() {
     // snapshot, the wrapping_sub would underflow.  The half-range guard
     // must detect this and skip the node.
     reset();
-    set_time(0);
+    set_mock_time(0);
     let mut wdg = new_wdg();
     unsafe {
         mwdg_assign_id(&mut wdg, 42);
@@ -613,7 +1057,7 @@ fn test_get_next_expired_after_feed_race() {
     }
 
     // Advance past timeout
-    set_time(200);
+    set_mock_time(200);
     assert_eq!(
         unsafe { mwdg_check() },
         1,
@@ -622,7 +1066,7 @@ fn test_get_next_expired_after_feed_race() {
 
     // Simulate the frozen task recovering and feeding its watchdog
     // AFTER mwdg_check but BEFORE iterating.
-    set_time(201);
+    set_mock_time(201);
     unsafe {
         mwdg_feed(&mut wdg);
     }
@@ -647,7 +1091,7 @@ fn test_get_next_expired_feed_race_does_not_falsely_report_healthy_node() {
     // the half-range guard the wrapping_sub would underflow and falsely
     // report the healthy node as expired.
     reset();
-    set_time(0);
+    set_mock_time(0);
     let mut wdg1 = new_wdg();
     let mut wdg2 = new_wdg();
     unsafe {
@@ -658,7 +1102,7 @@ fn test_get_next_expired_feed_race_does_not_falsely_report_healthy_node() {
     }
 
     // Feed wdg2 at t=350 so it stays healthy.
-    set_time(350);
+    set_mock_time(350);
     unsafe {
         mwdg_feed(&mut wdg2);
     }
@@ -666,11 +1110,11 @@ fn test_get_next_expired_feed_race_does_not_falsely_report_healthy_node() {
     // check() at t=450:
     //   wdg1: elapsed = 450 - 0   = 450 > 100 → expired
     //   wdg2: elapsed = 450 - 350 = 100 < 200 → healthy
-    set_time(450);
+    set_mock_time(450);
     assert_eq!(unsafe { mwdg_check() }, 1, "Should detect wdg1 expiration");
 
     // Simulate race: wdg2 is fed AFTER the snapshot.
-    set_time(460);
+    set_mock_time(460);
     unsafe {
         mwdg_feed(&mut wdg2);
     }
@@ -680,3 +1124,256 @@ fn test_get_next_expired_feed_race_does_not_falsely_report_healthy_node() {
     let ids = collect_expired_ids();
     assert_eq!(ids, vec![1], "Only wdg1 should be expired");
 }
+
+static CUSTOM_TIME: AtomicU32 = AtomicU32::new(0);
+
+extern "C" fn custom_clock() -> u32 {
+    CUSTOM_TIME.load(Ordering::Relaxed)
+}
+
+#[test]
+fn test_init_with_clock_uses_custom_clock() {
+    reset();
+    CUSTOM_TIME.store(1000, Ordering::Relaxed);
+    unsafe {
+        mwdg_init_with_clock(custom_clock);
+    }
+
+    let mut wdg = new_wdg();
+    safe_mwdg_add(&mut wdg, 100);
+
+    // Advancing the extern-symbol clock must have no effect.
+    set_mock_time(5000);
+    assert_eq!(
+        unsafe { mwdg_check() },
+        0,
+        "Extern clock must be ignored when a custom clock is registered"
+    );
+
+    CUSTOM_TIME.store(1150, Ordering::Relaxed);
+    assert_eq!(
+        unsafe { mwdg_check() },
+        1,
+        "Expiration must be evaluated against the custom clock"
+    );
+
+    // Restore the default time source for the remaining tests.
+    reset();
+}
+
+#[test]
+fn test_init_with_clock_feed_uses_custom_clock() {
+    reset();
+    CUSTOM_TIME.store(0, Ordering::Relaxed);
+    unsafe {
+        mwdg_init_with_clock(custom_clock);
+    }
+
+    let mut wdg = new_wdg();
+    safe_mwdg_add(&mut wdg, 100);
+
+    CUSTOM_TIME.store(80, Ordering::Relaxed);
+    unsafe {
+        mwdg_feed(&mut wdg);
+    }
+    CUSTOM_TIME.store(160, Ordering::Relaxed);
+    assert_eq!(
+        unsafe { mwdg_check() },
+        0,
+        "Should be OK because we fed at 80 on the custom clock"
+    );
+
+    reset();
+}
+
+#[test]
+fn test_init_reverts_to_extern_clock() {
+    reset();
+    CUSTOM_TIME.store(0, Ordering::Relaxed);
+    unsafe {
+        mwdg_init_with_clock(custom_clock);
+    }
+    // Plain init switches back to `mwdg_get_time_milliseconds`.
+    safe_mwdg_init();
+
+    let mut wdg = new_wdg();
+    safe_mwdg_add(&mut wdg, 100);
+    set_mock_time(150);
+    assert_eq!(
+        unsafe { mwdg_check() },
+        1,
+        "Extern clock must be used again"
+    );
+}
+
+#[test]
+fn test_get_time_to_expiry() {
+    reset();
+    set_mock_time(1000);
+    let mut fresh = new_wdg();
+    let mut near = new_wdg();
+    let mut past = new_wdg();
+
+    safe_mwdg_add(&mut near, 200);
+    safe_mwdg_add(&mut past, 100);
+
+    set_mock_time(1190);
+    safe_mwdg_add(&mut fresh, 500);
+
+    // fresh was just added, near has 10 ms left, past is 90 ms beyond timeout
+    assert_eq!(unsafe { mwdg_get_time_to_expiry(&mut fresh) }, 500);
+    assert_eq!(unsafe { mwdg_get_time_to_expiry(&mut near) }, 10);
+    assert_eq!(unsafe { mwdg_get_time_to_expiry(&mut past) }, 0);
+}
+
+#[test]
+fn test_get_time_to_expiry_null() {
+    reset();
+    assert_eq!(unsafe { mwdg_get_time_to_expiry(ptr::null_mut()) }, 0);
+}
+
+/// Helper: collect all registered IDs by iterating with mwdg_get_next_node.
+fn collect_warning_ids() -> Vec<u32> {
+    let mut ids = Vec::new();
+    let mut cursor: *mut mwdg_node = ptr::null_mut();
+    let mut id: u32 = 0;
+    while unsafe { mwdg_get_next_warning(&mut cursor, &mut id) } != 0 {
+        ids.push(id);
+    }
+    ids
+}
+
+#[test]
+fn test_get_next_warning_band() {
+    reset();
+    set_mock_time(0);
+    let mut wdg1 = new_wdg();
+    let mut wdg2 = new_wdg();
+    let mut wdg3 = new_wdg();
+    unsafe {
+        mwdg_assign_id(&mut wdg1, 1);
+        mwdg_assign_id(&mut wdg2, 2);
+        mwdg_assign_id(&mut wdg3, 3);
+        mwdg_set_warn_interval(&mut wdg1, 50);
+        mwdg_set_warn_interval(&mut wdg2, 50);
+    }
+    safe_mwdg_add(&mut wdg1, 100);
+    safe_mwdg_add(&mut wdg2, 300);
+    // No warning interval: never reported.
+    safe_mwdg_add(&mut wdg3, 100);
+
+    set_mock_time(40);
+    assert!(collect_warning_ids().is_empty());
+
+    set_mock_time(80);
+    assert_eq!(collect_warning_ids(), vec![1, 2]);
+
+    // wdg1 is now hard-expired and drops out of the warning band.
+    set_mock_time(150);
+    assert_eq!(collect_warning_ids(), vec![2]);
+    assert_eq!(unsafe { mwdg_is_expired() }, 0);
+}
+
+#[test]
+fn test_get_next_warning_null_args() {
+    reset();
+    let mut cursor: *mut mwdg_node = ptr::null_mut();
+    let mut id: u32 = 0;
+    assert_eq!(
+        unsafe { mwdg_get_next_warning(ptr::null_mut(), &mut id) },
+        0
+    );
+    assert_eq!(
+        unsafe { mwdg_get_next_warning(&mut cursor, ptr::null_mut()) },
+        0
+    );
+}
+
+fn collect_node_ids() -> Vec<u32> {
+    let mut ids = Vec::new();
+    let mut cursor: *mut mwdg_node = ptr::null_mut();
+    let mut id: u32 = 0;
+    while unsafe { mwdg_get_next_node(&mut cursor, &mut id) } == 1 {
+        ids.push(id);
+    }
+    ids
+}
+
+#[test]
+fn test_get_next_node_empty_list() {
+    reset();
+    assert!(collect_node_ids().is_empty());
+}
+
+#[test]
+fn test_get_next_node_enumerates_all() {
+    reset();
+    set_mock_time(0);
+    let mut wdg1 = new_wdg();
+    let mut wdg2 = new_wdg();
+    let mut wdg3 = new_wdg();
+
+    unsafe {
+        mwdg_assign_id(&mut wdg1, 10);
+        mwdg_assign_id(&mut wdg2, 20);
+        mwdg_assign_id(&mut wdg3, 30);
+    }
+    safe_mwdg_add(&mut wdg1, 100);
+    safe_mwdg_add(&mut wdg2, 200);
+    safe_mwdg_add(&mut wdg3, 300);
+
+    // Healthy nodes are listed without a prior mwdg_check.
+    assert_eq!(collect_node_ids(), vec![10, 20, 30]);
+
+    // Expired nodes are listed too.
+    set_mock_time(250);
+    assert_eq!(unsafe { mwdg_check() }, 1);
+    assert_eq!(collect_node_ids(), vec![10, 20, 30]);
+}
+
+#[test]
+fn test_get_next_node_restarts_after_cursor_node_removed() {
+    reset();
+    set_mock_time(0);
+    let mut wdg1 = new_wdg();
+    let mut wdg2 = new_wdg();
+    let mut wdg3 = new_wdg();
+
+    unsafe {
+        mwdg_assign_id(&mut wdg1, 10);
+        mwdg_assign_id(&mut wdg2, 20);
+        mwdg_assign_id(&mut wdg3, 30);
+    }
+    safe_mwdg_add(&mut wdg1, 100);
+    safe_mwdg_add(&mut wdg2, 200);
+    safe_mwdg_add(&mut wdg3, 300);
+
+    let mut cursor: *mut mwdg_node = ptr::null_mut();
+    let mut id: u32 = 0;
+    assert_eq!(unsafe { mwdg_get_next_node(&mut cursor, &mut id) }, 1);
+    assert_eq!(unsafe { mwdg_get_next_node(&mut cursor, &mut id) }, 1);
+    assert_eq!(id, 20);
+
+    // The cursor's node is gone: the next call restarts from the head.
+    unsafe { mwdg_remove(&mut wdg2) };
+    assert_eq!(unsafe { mwdg_get_next_node(&mut cursor, &mut id) }, 1);
+    assert_eq!(id, 10);
+    assert_eq!(unsafe { mwdg_get_next_node(&mut cursor, &mut id) }, 1);
+    assert_eq!(id, 30);
+    assert_eq!(unsafe { mwdg_get_next_node(&mut cursor, &mut id) }, 0);
+}
+
+#[test]
+fn test_get_next_node_null_args() {
+    reset();
+    let mut wdg = new_wdg();
+    safe_mwdg_add(&mut wdg, 100);
+
+    let mut cursor: *mut mwdg_node = ptr::null_mut();
+    let mut id: u32 = 0;
+    assert_eq!(unsafe { mwdg_get_next_node(ptr::null_mut(), &mut id) }, 0);
+    assert_eq!(
+        unsafe { mwdg_get_next_node(&mut cursor, ptr::null_mut()) },
+        0
+    );
+}
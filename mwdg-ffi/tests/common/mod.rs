@@ -0,0 +1,39 @@
+//! Test utilities shared by the `mwdg-ffi` integration tests.
+//!
+//! Provides the user-supplied C symbols the shim links against — a mock
+//! millisecond clock and no-op critical section hooks — so each test binary
+//! does not have to define them itself. Include it with `mod common;`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+static MOCK_TIME: AtomicU32 = AtomicU32::new(0);
+
+/// Set the mock clock returned by `mwdg_get_time_milliseconds`.
+pub fn set_mock_time(ms: u32) {
+    MOCK_TIME.store(ms, Ordering::Relaxed);
+}
+
+/// Advance the mock clock by `ms`, wrapping around `u32::MAX` like a real
+/// tick counter.
+pub fn advance_mock_time(ms: u32) {
+    let now = MOCK_TIME.load(Ordering::Relaxed);
+    MOCK_TIME.store(now.wrapping_add(ms), Ordering::Relaxed);
+}
+
+/// User-provided function that returns the current time in milliseconds.
+#[unsafe(no_mangle)]
+pub extern "C" fn mwdg_get_time_milliseconds() -> u32 {
+    MOCK_TIME.load(Ordering::Relaxed)
+}
+
+/// User-provided function to enter a critical section.
+#[unsafe(no_mangle)]
+pub extern "C" fn mwdg_enter_critical() {
+    // no-op for single-threaded tests
+}
+
+/// User-provided function to exit a critical section.
+#[unsafe(no_mangle)]
+pub extern "C" fn mwdg_exit_critical() {
+    // no-op for single-threaded tests
+}
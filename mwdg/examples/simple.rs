@@ -25,12 +25,11 @@
 //! ```sh
 //! cargo run -p mwdg --example simple
 //! ```
-use std::ptr;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
-use mwdg::{WatchdogNode, WatchdogRegistry};
+use mwdg::{Cursor, WatchdogNode, WatchdogRegistry};
 
 /// Returns milliseconds elapsed since `origin` (wraps at `u32::MAX`).
 fn now_ms(origin: Instant) -> u32 {
@@ -128,7 +127,7 @@ fn main() {
         // If expired, iterate to find which watchdog(s) caused it.
         if status {
             let reg = registry.lock().unwrap();
-            let mut cursor: *const WatchdogNode = ptr::null();
+            let mut cursor = Cursor::start();
             while let Some(id) = reg.next_expired(&mut cursor) {
                 println!("[main]   expired watchdog id: {id}");
             }
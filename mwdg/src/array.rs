@@ -0,0 +1,360 @@
+//! Fixed-capacity, array-backed watchdog registry.
+//!
+//! [`ArrayRegistryT`] is an alternative to the intrusive
+//! [`WatchdogRegistryT`](crate::WatchdogRegistryT) for callers that register
+//! a handful of watchdogs from a single task and do not want to manage
+//! pinned nodes. Each watchdog is identified by its id and stored inline, so
+//! there are no pointers and no pinning; the price is a capacity fixed at
+//! compile time.
+
+use core::fmt;
+
+use crate::{WrappingTime, elapsed_since};
+
+/// An array-backed registry with `u32` millisecond timestamps.
+///
+/// See [`ArrayRegistryT`] for details.
+pub type ArrayRegistry<const N: usize> = ArrayRegistryT<u32, N>;
+
+/// Error returned by [`ArrayRegistryT::add`] when all `N` slots are in use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CapacityError;
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("watchdog registry is full")
+    }
+}
+
+impl core::error::Error for CapacityError {}
+
+/// A registered watchdog stored inline in an [`ArrayRegistryT`] slot.
+#[derive(Clone, Copy)]
+struct Entry<T: WrappingTime> {
+    id: u32,
+    timeout_interval_ms: T,
+    last_touched_timestamp_ms: T,
+}
+
+/// A registry of up to `N` watchdogs stored inline, identified by id.
+///
+/// The semantics mirror [`WatchdogRegistryT`](crate::WatchdogRegistryT):
+/// elapsed time uses wrapping arithmetic, [`check`](Self::check) latches
+/// once any watchdog has expired, and [`next_expired`](Self::next_expired)
+/// evaluates entries against the timestamp captured by `check`.
+///
+/// # Usage
+///
+/// ```rust
+/// use mwdg::ArrayRegistry;
+///
+/// let mut registry = ArrayRegistry::<4>::new();
+/// registry.add(1, 200, 0).unwrap();
+///
+/// registry.feed(1, 150);
+/// assert!(!registry.check(300));
+/// ```
+pub struct ArrayRegistryT<T: WrappingTime, const N: usize> {
+    /// Registered watchdogs. `None` marks a free slot.
+    entries: [Option<Entry<T>>; N],
+    /// Whether any registered watchdog has expired (latching, like the list
+    /// registry).
+    expired: bool,
+    /// Timestamp (ms) captured by [`check`](Self::check) at the moment it
+    /// first detected an expiration.
+    expired_at_ms: T,
+}
+
+impl<T: WrappingTime, const N: usize> Default for ArrayRegistryT<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: WrappingTime, const N: usize> ArrayRegistryT<T, N> {
+    /// Create a new, empty registry.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: [None; N],
+            expired: false,
+            expired_at_ms: T::ZERO,
+        }
+    }
+
+    /// Returns the number of registered watchdogs.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.iter().flatten().count()
+    }
+
+    /// Returns `true` if no watchdog is registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.iter().all(Option::is_none)
+    }
+
+    /// Returns `true` if the registry has latched into the expired state.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.expired
+    }
+
+    /// Register a watchdog with the given id and timeout.
+    ///
+    /// Its last-feed timestamp is set to `now`. If a watchdog with the same
+    /// id is already registered, the call acts as a combined
+    /// [`feed`](Self::feed) + timeout update instead of taking a second slot.
+    ///
+    /// # Parameters
+    /// - `id`: the identifier of the watchdog.
+    /// - `timeout_ms`: timeout interval in milliseconds.
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Errors
+    /// Returns [`CapacityError`] if the id is new and all `N` slots are
+    /// taken.
+    pub fn add(&mut self, id: u32, timeout_ms: T, now: T) -> Result<(), CapacityError> {
+        let entry = Entry {
+            id,
+            timeout_interval_ms: timeout_ms,
+            last_touched_timestamp_ms: now,
+        };
+
+        if let Some(existing) = self.find_mut(id) {
+            *existing = entry;
+            return Ok(());
+        }
+
+        let slot = self
+            .entries
+            .iter_mut()
+            .find(|slot| slot.is_none())
+            .ok_or(CapacityError)?;
+        *slot = Some(entry);
+
+        Ok(())
+    }
+
+    /// Unregister the watchdog with the given id, freeing its slot.
+    ///
+    /// # Returns
+    /// `true` if a watchdog with the given id was registered.
+    pub fn remove(&mut self, id: u32) -> bool {
+        match self
+            .entries
+            .iter_mut()
+            .find(|slot| slot.is_some_and(|entry| entry.id == id))
+        {
+            Some(slot) => {
+                *slot = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Feed (touch) the watchdog with the given id, resetting its timestamp
+    /// to `now`.
+    ///
+    /// # Returns
+    /// `true` if a watchdog with the given id was found and fed.
+    pub fn feed(&mut self, id: u32, now: T) -> bool {
+        match self.find_mut(id) {
+            Some(entry) => {
+                entry.last_touched_timestamp_ms = now;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Check all registered watchdogs for expiration.
+    ///
+    /// Once an expiration is detected the registry latches: subsequent calls
+    /// return `true` without re-scanning, and the detection timestamp is
+    /// frozen for [`next_expired`](Self::next_expired).
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// `true` if any watchdog has expired, `false` if all are healthy.
+    pub fn check(&mut self, now: T) -> bool {
+        if self.expired {
+            return true;
+        }
+
+        if self.entries.iter().flatten().any(|entry| {
+            elapsed_since(now, entry.last_touched_timestamp_ms)
+                .is_some_and(|elapsed| elapsed > entry.timeout_interval_ms)
+        }) {
+            self.expired = true;
+            self.expired_at_ms = now;
+        }
+
+        self.expired
+    }
+
+    /// Get the next expired watchdog in the iteration.
+    ///
+    /// `cursor` is the slot index to resume from; initialise it to `0`
+    /// before the first call. Entries are evaluated against the timestamp
+    /// captured by [`check`](Self::check), with the same half-range guard as
+    /// [`WatchdogRegistryT::next_expired`](crate::WatchdogRegistryT::next_expired)
+    /// for watchdogs fed after that snapshot.
+    ///
+    /// # Returns
+    /// - `Some(id)` if an expired watchdog was found; `cursor` is advanced
+    ///   past it.
+    /// - `None` when no more expired watchdogs remain, or if `check` has not
+    ///   yet detected an expiration.
+    pub fn next_expired(&self, cursor: &mut usize) -> Option<u32> {
+        if !self.expired {
+            return None;
+        }
+
+        let now = self.expired_at_ms;
+        while let Some(slot) = self.entries.get(*cursor) {
+            *cursor += 1;
+            if let Some(entry) = slot
+                && elapsed_since(now, entry.last_touched_timestamp_ms)
+                    .is_some_and(|elapsed| elapsed > entry.timeout_interval_ms)
+            {
+                return Some(entry.id);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the registered entry with the given id, if any.
+    fn find_mut(&mut self, id: u32) -> Option<&mut Entry<T>> {
+        self.entries
+            .iter_mut()
+            .flatten()
+            .find(|entry| entry.id == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cursor, WatchdogNode, WatchdogRegistry};
+    use core::pin::Pin;
+
+    #[test]
+    fn test_add_until_full() {
+        let mut reg = ArrayRegistry::<2>::new();
+
+        assert!(reg.is_empty());
+        assert_eq!(reg.add(1, 100, 0), Ok(()));
+        assert_eq!(reg.add(2, 100, 0), Ok(()));
+        assert_eq!(reg.add(3, 100, 0), Err(CapacityError));
+        assert_eq!(reg.len(), 2);
+
+        // Re-adding a known id does not need a free slot.
+        assert_eq!(reg.add(2, 300, 50), Ok(()));
+        assert_eq!(reg.len(), 2);
+
+        // Removing frees a slot for a new id.
+        assert!(reg.remove(1));
+        assert!(!reg.remove(1));
+        assert_eq!(reg.add(3, 100, 0), Ok(()));
+    }
+
+    #[test]
+    fn test_feed_unknown_id() {
+        let mut reg = ArrayRegistry::<2>::new();
+
+        assert!(!reg.feed(1, 10));
+    }
+
+    #[test]
+    fn test_parity_with_list_registry() {
+        let mut array = ArrayRegistry::<3>::new();
+        let mut list = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+        // SAFETY: the nodes outlive the registry and are never moved.
+        let (mut p1, mut p2, mut p3) = unsafe {
+            (
+                Pin::new_unchecked(&mut n1),
+                Pin::new_unchecked(&mut n2),
+                Pin::new_unchecked(&mut n3),
+            )
+        };
+
+        for (id, node, timeout) in [
+            (1, p1.as_mut(), 100),
+            (2, p2.as_mut(), 500),
+            (3, p3.as_mut(), 100),
+        ] {
+            array.add(id, timeout, 0).unwrap();
+            list.add(node, timeout, 0);
+        }
+
+        array.feed(3, 90);
+        WatchdogRegistry::feed(p3.as_mut(), 90);
+
+        for now in [50, 100, 150, 190, 191] {
+            assert_eq!(array.check(now), list.check(now), "now = {now}");
+        }
+
+        let mut index = 0;
+        let mut cursor = Cursor::start();
+        loop {
+            let expected = list.next_expired(&mut cursor);
+            assert_eq!(array.next_expired(&mut index), expected);
+            if expected.is_none() {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrapping_no_expire() {
+        let mut reg = ArrayRegistry::<1>::new();
+
+        reg.add(1, 100, u32::MAX - 50).unwrap();
+        // Wrapped elapsed time is 100, exactly the timeout.
+        assert!(!reg.check(49));
+    }
+
+    #[test]
+    fn test_wrapping_expired() {
+        let mut reg = ArrayRegistry::<1>::new();
+
+        reg.add(1, 100, u32::MAX - 50).unwrap();
+        assert!(reg.check(50));
+
+        let mut cursor = 0;
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+        assert_eq!(reg.next_expired(&mut cursor), None);
+    }
+
+    #[test]
+    fn test_next_expired_skips_fed_after_snapshot() {
+        let mut reg = ArrayRegistry::<2>::new();
+
+        reg.add(1, 100, 0).unwrap();
+        reg.add(2, 100, 0).unwrap();
+        assert!(reg.check(150));
+
+        // Fed after the snapshot: not reported.
+        reg.feed(1, 200);
+        let mut cursor = 0;
+        assert_eq!(reg.next_expired(&mut cursor), Some(2));
+        assert_eq!(reg.next_expired(&mut cursor), None);
+    }
+
+    #[test]
+    fn test_next_expired_before_check() {
+        let mut reg = ArrayRegistry::<1>::new();
+
+        reg.add(1, 100, 0).unwrap();
+        let mut cursor = 0;
+        assert_eq!(reg.next_expired(&mut cursor), None);
+    }
+}
@@ -0,0 +1,202 @@
+//! Registry wrapper that serializes every call through a critical section.
+//!
+//! The core registry relies on `&mut self` for exclusive access and leaves
+//! interrupt/task-level locking to the caller. [`RegistryGuardedT`] bundles a
+//! [`WatchdogRegistryT`] with a user-provided [`CriticalSection`] so the
+//! locking discipline lives in one place and can be exercised with a mock
+//! implementation in tests.
+
+use core::pin::Pin;
+
+use crate::{CursorT, WatchdogNodeT, WatchdogRegistryT, WrappingTime};
+
+/// A pair of enter/exit hooks delimiting a critical section.
+///
+/// Implementations typically disable interrupts or take an RTOS lock in
+/// [`enter`](Self::enter) and undo it in [`exit`](Self::exit). Calls are
+/// always balanced: every `enter` is followed by exactly one `exit`.
+pub trait CriticalSection {
+    /// Enter the critical section.
+    fn enter(&self);
+
+    /// Exit the critical section entered by the matching
+    /// [`enter`](Self::enter).
+    fn exit(&self);
+}
+
+/// A guarded registry with `u32` millisecond timestamps.
+///
+/// See [`RegistryGuardedT`] for details.
+pub type RegistryGuarded<C> = RegistryGuardedT<C, u32>;
+
+/// A [`WatchdogRegistryT`] whose every method runs inside the critical
+/// section `C`.
+///
+/// # Usage
+///
+/// ```rust
+/// use mwdg::{CriticalSection, RegistryGuarded, WatchdogNode};
+/// use core::pin::pin;
+///
+/// struct NoLock;
+///
+/// impl CriticalSection for NoLock {
+///     fn enter(&self) {}
+///     fn exit(&self) {}
+/// }
+///
+/// let mut registry = RegistryGuarded::new(NoLock);
+/// let mut node = pin!(WatchdogNode::default());
+/// registry.add(node.as_mut(), 100, 0);
+/// assert!(!registry.check(50));
+/// ```
+pub struct RegistryGuardedT<C: CriticalSection, T: WrappingTime> {
+    registry: WatchdogRegistryT<T>,
+    cs: C,
+}
+
+/// Exits the critical section when dropped, so `enter`/`exit` stay balanced
+/// even if the guarded call unwinds.
+struct Section<'a, C: CriticalSection>(&'a C);
+
+impl<'a, C: CriticalSection> Section<'a, C> {
+    fn enter(cs: &'a C) -> Self {
+        cs.enter();
+        Self(cs)
+    }
+}
+
+impl<C: CriticalSection> Drop for Section<'_, C> {
+    fn drop(&mut self) {
+        self.0.exit();
+    }
+}
+
+impl<C: CriticalSection, T: WrappingTime> RegistryGuardedT<C, T> {
+    /// Create a new, empty guarded registry using `cs` for locking.
+    #[must_use]
+    pub const fn new(cs: C) -> Self {
+        Self {
+            registry: WatchdogRegistryT::new(),
+            cs,
+        }
+    }
+
+    /// Run `f` with the inner registry inside the critical section.
+    ///
+    /// Use this for registry methods without a dedicated wrapper.
+    pub fn lock<R>(&mut self, f: impl FnOnce(&mut WatchdogRegistryT<T>) -> R) -> R {
+        let _section = Section::enter(&self.cs);
+        f(&mut self.registry)
+    }
+
+    /// Critical-section wrapper for [`WatchdogRegistryT::add`].
+    pub fn add(&mut self, node: Pin<&mut WatchdogNodeT<T>>, timeout_ms: T, now: T) {
+        self.lock(|registry| registry.add(node, timeout_ms, now));
+    }
+
+    /// Critical-section wrapper for [`WatchdogRegistryT::remove`].
+    pub fn remove(&mut self, node: Pin<&mut WatchdogNodeT<T>>) {
+        self.lock(|registry| registry.remove(node));
+    }
+
+    /// Critical-section wrapper for [`WatchdogRegistryT::feed`].
+    pub fn feed(&mut self, node: Pin<&mut WatchdogNodeT<T>>, now: T) {
+        self.lock(|_| WatchdogRegistryT::feed(node, now));
+    }
+
+    /// Critical-section wrapper for [`WatchdogRegistryT::check`].
+    pub fn check(&mut self, now: T) -> bool {
+        self.lock(|registry| registry.check(now))
+    }
+
+    /// Critical-section wrapper for [`WatchdogRegistryT::clear_expired`].
+    pub fn clear_expired(&mut self) {
+        self.lock(WatchdogRegistryT::clear_expired);
+    }
+
+    /// Critical-section wrapper for [`WatchdogRegistryT::next_expired`].
+    pub fn next_expired(&mut self, cursor: &mut CursorT<T>) -> Option<u32> {
+        self.lock(|registry| registry.next_expired(cursor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cursor, WatchdogNode};
+    use core::cell::Cell;
+
+    /// `enter`/`exit` call counts and the current nesting depth.
+    #[derive(Default)]
+    struct Counters {
+        enters: Cell<u32>,
+        exits: Cell<u32>,
+        depth: Cell<u32>,
+    }
+
+    impl Counters {
+        fn assert_balanced(&self, calls: u32) {
+            assert_eq!(self.enters.get(), calls);
+            assert_eq!(self.exits.get(), calls);
+            assert_eq!(self.depth.get(), 0);
+        }
+    }
+
+    /// Mock critical section recording into borrowed [`Counters`], so the
+    /// test can inspect them while the registry is borrowed.
+    struct CountingSection<'a>(&'a Counters);
+
+    impl CriticalSection for CountingSection<'_> {
+        fn enter(&self) {
+            self.0.enters.set(self.0.enters.get() + 1);
+            self.0.depth.set(self.0.depth.get() + 1);
+        }
+
+        fn exit(&self) {
+            self.0.exits.set(self.0.exits.get() + 1);
+            self.0.depth.set(self.0.depth.get() - 1);
+        }
+    }
+
+    #[test]
+    fn test_enter_exit_balanced_around_every_call() {
+        let counters = Counters::default();
+        let mut reg = RegistryGuarded::new(CountingSection(&counters));
+        let mut n = WatchdogNode::builder().id(1).build();
+        // SAFETY: `n` outlives the registry usage and is never moved.
+        let mut pinned = unsafe { Pin::new_unchecked(&mut n) };
+
+        reg.add(pinned.as_mut(), 100, 0);
+        counters.assert_balanced(1);
+
+        reg.feed(pinned.as_mut(), 50);
+        counters.assert_balanced(2);
+
+        assert!(!reg.check(150));
+        counters.assert_balanced(3);
+
+        assert!(reg.check(151));
+        counters.assert_balanced(4);
+
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+        counters.assert_balanced(5);
+
+        reg.clear_expired();
+        counters.assert_balanced(6);
+
+        reg.remove(pinned.as_mut());
+        counters.assert_balanced(7);
+    }
+
+    #[test]
+    fn test_lock_runs_inside_section() {
+        let counters = Counters::default();
+        let mut reg = RegistryGuarded::new(CountingSection(&counters));
+
+        let depth = reg.lock(|_| counters.depth.get());
+        assert_eq!(depth, 1);
+        counters.assert_balanced(1);
+    }
+}
@@ -10,7 +10,7 @@
 //!
 //! ## Design
 //!
-//! The registry maintains an intrusive singly-linked list of watchdog nodes.
+//! The registry maintains an intrusive doubly-linked list of watchdog nodes.
 //! All raw-pointer manipulation is encapsulated behind a safe public API:
 //!
 //! - [`Pin<&mut WatchdogNode>`] prevents nodes from being moved while they
@@ -18,16 +18,147 @@
 //! - `&mut self` on registry methods ensures exclusive access, eliminating
 //!   data races without requiring a critical section in the library itself.
 //!
+//! ## Timestamp width
+//!
+//! Timestamps and timeouts are generic over the [`WrappingTime`] trait,
+//! implemented for `u32` and `u64`. [`WatchdogNode`] and [`WatchdogRegistry`]
+//! are aliases for the `u32` instantiation; use [`WatchdogNodeT<u64>`] and
+//! [`WatchdogRegistryT<u64>`] on platforms with a 64-bit millisecond tick to
+//! avoid reasoning about the ~49 day `u32` wrap.
+//!
 //! This crate contains **no** global state, **no** C FFI, and **no** unsafe
 //! in its public interface. The companion `mwdg-ffi` crate provides the C
 //! shim layer on top of this API.
 
 #![no_std]
 
-use core::marker::PhantomPinned;
+use core::fmt;
+use core::marker::{PhantomData, PhantomPinned};
 use core::pin::Pin;
 use core::ptr;
 
+mod array;
+mod guarded;
+
+pub use array::{ArrayRegistry, ArrayRegistryT, CapacityError};
+pub use guarded::{CriticalSection, RegistryGuarded, RegistryGuardedT};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+}
+
+/// Unsigned integer type used for watchdog timestamps and timeouts.
+///
+/// All elapsed-time computations use wrapping arithmetic, so a timestamp
+/// source that overflows back to zero is handled transparently as long as
+/// no node goes unchecked for more than half of the type's range.
+///
+/// This trait is sealed and implemented for `u32` and `u64` only.
+pub trait WrappingTime: sealed::Sealed + Copy + Ord + fmt::Debug {
+    /// The zero timestamp / duration.
+    const ZERO: Self;
+    /// The largest representable timestamp / duration.
+    const MAX: Self;
+    /// Half of the type's range. A wrapped elapsed time above this value is
+    /// interpreted as a timestamp lying in the *future*.
+    const HALF_RANGE: Self;
+
+    /// Wrapping (modular) subtraction.
+    #[must_use]
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Wrapping (modular) addition.
+    #[must_use]
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Saturating subtraction, clamping at [`ZERO`](Self::ZERO).
+    #[must_use]
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    /// Saturating addition, clamping at [`MAX`](Self::MAX).
+    #[must_use]
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Integer division by a non-zero count.
+    #[must_use]
+    fn div_count(self, count: u32) -> Self;
+
+    /// Saturating multiplication by a scale factor, clamping at
+    /// [`MAX`](Self::MAX).
+    #[must_use]
+    fn saturating_scale(self, factor: u32) -> Self;
+}
+
+macro_rules! impl_wrapping_time {
+    ($($ty:ty),*) => {
+        $(
+            impl WrappingTime for $ty {
+                const ZERO: Self = 0;
+                const MAX: Self = <$ty>::MAX;
+                const HALF_RANGE: Self = <$ty>::MAX / 2;
+
+                #[inline]
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    <$ty>::wrapping_sub(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$ty>::wrapping_add(self, rhs)
+                }
+
+                #[inline]
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$ty>::saturating_sub(self, rhs)
+                }
+
+                #[inline]
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$ty>::saturating_add(self, rhs)
+                }
+
+                #[inline]
+                fn div_count(self, count: u32) -> Self {
+                    self / <$ty>::from(count)
+                }
+
+                #[inline]
+                fn saturating_scale(self, factor: u32) -> Self {
+                    <$ty>::saturating_mul(self, <$ty>::from(factor))
+                }
+            }
+        )*
+    };
+}
+
+impl_wrapping_time!(u32, u64);
+
+/// Time elapsed from `then` to `now` with wrapping arithmetic, or `None` if
+/// `then` lies in the future relative to `now`.
+///
+/// A `then` slightly *after* `now` (e.g. a node fed after a timestamp
+/// snapshot was taken) makes `wrapping_sub` underflow into the upper half of
+/// the range. Any difference above [`HALF_RANGE`](WrappingTime::HALF_RANGE)
+/// is therefore treated as "in the future" rather than as an enormous
+/// elapsed time.
+pub(crate) fn elapsed_since<T: WrappingTime>(now: T, then: T) -> Option<T> {
+    let elapsed = now.wrapping_sub(then);
+    (elapsed <= T::HALF_RANGE).then_some(elapsed)
+}
+
+/// A watchdog node with `u32` millisecond timestamps.
+///
+/// See [`WatchdogNodeT`] for details.
+pub type WatchdogNode = WatchdogNodeT<u32>;
+
+/// A registry of watchdog nodes with `u32` millisecond timestamps.
+///
+/// See [`WatchdogRegistryT`] for details.
+pub type WatchdogRegistry = WatchdogRegistryT<u32>;
+
 /// A single software watchdog node.
 ///
 /// Each RTOS/async task owns one of these (typically as a `static` or a long-lived
@@ -41,6 +172,9 @@ use core::ptr;
 /// enforces this by requiring [`Pin<&mut WatchdogNode>`] for all mutating
 /// operations.
 ///
+/// The timestamp type `T` is usually inferred from the registry the node is
+/// added to; see [`WrappingTime`].
+///
 /// `WatchdogNode` deliberately implements `!Unpin` (via [`PhantomPinned`]) so
 /// that [`Pin`] provides its full move-prevention guarantee.
 ///
@@ -49,13 +183,14 @@ use core::ptr;
 /// assert_unpin::<mwdg::WatchdogNode>(); // must not compile
 /// ```
 #[repr(C)]
-pub struct WatchdogNode {
-    /// Timeout interval in milliseconds. Set during [`WatchdogRegistry::add`].
-    timeout_interval_ms: u32,
+pub struct WatchdogNodeT<T: WrappingTime> {
+    /// Timeout interval in milliseconds. Set during [`WatchdogRegistry::add`]
+    /// or up front via [`WatchdogNodeT::builder`].
+    timeout_interval_ms: T,
 
     /// Timestamp (ms) of the last feed. Updated by [`WatchdogRegistry::feed`]
     /// and [`WatchdogRegistry::add`].
-    last_touched_timestamp_ms: u32,
+    last_touched_timestamp_ms: T,
 
     /// User-assigned identifier for this watchdog node.
     /// Set via [`WatchdogRegistry::assign_id`]. Defaults to `0`.
@@ -64,28 +199,163 @@ pub struct WatchdogNode {
     /// [`WatchdogRegistry::next_expired`].
     id: u32,
 
+    /// Early-warning interval in milliseconds. `0` disables the warning.
+    /// Set via [`WatchdogRegistry::set_warn_interval`].
+    warn_interval_ms: T,
+
+    /// Smallest remaining time (ms) observed by
+    /// [`WatchdogRegistry::check_all`] since the node was added (high-water
+    /// mark of timeout usage). `T::MAX` until the first full scan.
+    min_margin_ms: T,
+
+    /// Minimum interval (ms) expected between two consecutive feeds. `0`
+    /// disables the early-feed check. Set via [`WatchdogRegistry::set_window`].
+    min_interval_ms: T,
+
+    /// Interval (ms) between the last two feeds. `T::MAX` until the node has
+    /// been fed twice since it was added or resumed.
+    feed_gap_ms: T,
+
+    /// Sum (ms, saturating) of the intervals between consecutive feeds since
+    /// the node was added. See [`WatchdogNodeT::avg_feed_gap_ms`].
+    accumulated_gap_ms: T,
+
+    /// Number of feeds (saturating) since the node was added.
+    feed_count: u32,
+
+    /// Number of full scans (saturating) that found the node expired. Never
+    /// reset by the library. See [`WatchdogNodeT::expiry_count`].
+    expiry_count: u32,
+
+    /// Whether the node is unregistered by [`WatchdogRegistry::check`] once
+    /// it expires instead of latching the registry.
+    /// Set via [`WatchdogRegistry::set_one_shot`]. Defaults to `false`.
+    one_shot: bool,
+
+    /// Whether monitoring of the node is suspended. Paused nodes are
+    /// skipped by the checks and never expire.
+    /// Set via [`WatchdogRegistry::pause`] / [`WatchdogRegistry::resume`].
+    paused: bool,
+
+    /// Whether the node has been fed since it was added or resumed, i.e.
+    /// whether the next feed closes a measurable [`Self::feed_gap_ms`].
+    fed: bool,
+
+    /// Whether the node is currently linked into a registry. Set by
+    /// [`WatchdogRegistry::add`] and cleared when the node is removed.
+    linked: bool,
+
+    /// Whether the node tracks an absolute deadline rather than a periodic
+    /// heartbeat. Set by [`WatchdogRegistry::add_deadline`] and cleared by
+    /// [`WatchdogRegistry::add`]; feeds leave such a node's timing untouched.
+    deadline: bool,
+
+    /// User-assigned priority / severity class. Defaults to `0`.
+    /// Set via [`WatchdogRegistry::set_priority`]. Like `id`, the library
+    /// never interprets it; it is reported by
+    /// [`WatchdogRegistry::next_expired_with_priority`].
+    priority: u8,
+
+    /// Group (subsystem) the node belongs to. Defaults to `0`.
+    /// Set via [`WatchdogRegistry::set_group`] and scanned selectively by
+    /// [`WatchdogRegistry::check_group`].
+    group: u8,
+
+    /// Number of consecutive healthy [`WatchdogRegistry::update_health`]
+    /// scans since the node was last seen unhealthy. Reset to `u16::MAX`
+    /// ("fully recovered") when the node is added.
+    healthy_streak: u16,
+
+    /// Healthy scans required before a degraded node recovers.
+    /// Set via [`WatchdogRegistry::set_recover_after`]. Defaults to `0`.
+    recover_after: u16,
+
+    /// Number of [`WatchdogRegistry::check`] calls since the node was last
+    /// fed. The first of them found the feed and is not a miss. Only
+    /// counted for nodes registered via [`WatchdogRegistry::add_by_misses`].
+    missed_checks: u16,
+
+    /// Consecutive missed checks after which the node expires, or `0` for
+    /// a regular time-based node. Set by [`WatchdogRegistry::add_by_misses`]
+    /// and cleared by [`WatchdogRegistry::add`].
+    max_missed: u16,
+
+    /// Optional human-readable name for diagnostics. Defaults to `None`.
+    /// Set via [`WatchdogRegistry::set_name`].
+    #[cfg(feature = "names")]
+    name: Option<&'static str>,
+
     /// Intrusive linked-list pointer to the next registered watchdog.
     /// Null if this node is the tail of the list or is not registered.
-    next: *mut WatchdogNode,
+    next: *mut WatchdogNodeT<T>,
+
+    /// Intrusive linked-list pointer to the previous registered watchdog,
+    /// so that [`WatchdogRegistry::remove_unchecked`] can unlink the node in
+    /// constant time. Null if this node is the head of the list or is not
+    /// registered.
+    prev: *mut WatchdogNodeT<T>,
 
     /// Marker to make `WatchdogNode` `!Unpin`, so that [`Pin`] actually
     /// prevents moves in safe code.
     _pin: PhantomPinned,
 }
 
-impl Default for WatchdogNode {
+impl<T: WrappingTime> Default for WatchdogNodeT<T> {
     fn default() -> Self {
         Self {
-            timeout_interval_ms: 0,
-            last_touched_timestamp_ms: 0,
+            timeout_interval_ms: T::ZERO,
+            last_touched_timestamp_ms: T::ZERO,
             id: 0,
+            warn_interval_ms: T::ZERO,
+            min_margin_ms: T::MAX,
+            min_interval_ms: T::ZERO,
+            feed_gap_ms: T::MAX,
+            accumulated_gap_ms: T::ZERO,
+            feed_count: 0,
+            expiry_count: 0,
+            one_shot: false,
+            paused: false,
+            fed: false,
+            linked: false,
+            deadline: false,
+            priority: 0,
+            group: 0,
+            healthy_streak: u16::MAX,
+            recover_after: 0,
+            missed_checks: 0,
+            max_missed: 0,
+            #[cfg(feature = "names")]
+            name: None,
             next: ptr::null_mut(),
+            prev: ptr::null_mut(),
             _pin: PhantomPinned,
         }
     }
 }
 
-impl WatchdogNode {
+impl<T: WrappingTime> WatchdogNodeT<T> {
+    /// Returns a builder for a node with its fields pre-set before pinning.
+    ///
+    /// ```rust
+    /// use mwdg::{WatchdogNode, WatchdogRegistry};
+    /// use core::pin::pin;
+    ///
+    /// let mut registry = WatchdogRegistry::new();
+    /// let mut node = pin!(WatchdogNode::builder().id(3).timeout_ms(200).build());
+    /// registry.add_configured(node.as_mut(), 0);
+    /// assert_eq!(node.timeout_interval_ms(), 200);
+    /// ```
+    #[must_use]
+    pub const fn builder() -> WatchdogNodeBuilder<T> {
+        WatchdogNodeBuilder {
+            id: 0,
+            timeout_ms: T::ZERO,
+            warn_interval_ms: T::ZERO,
+            one_shot: false,
+            priority: 0,
+        }
+    }
+
     /// Returns the user-assigned identifier of this watchdog node.
     ///
     /// The identifier is set via [`WatchdogRegistry::assign_id`] and defaults
@@ -94,6 +364,504 @@ impl WatchdogNode {
     pub fn id(&self) -> u32 {
         self.id
     }
+
+    /// Returns the timeout interval of this watchdog node in milliseconds.
+    ///
+    /// The interval is set via [`WatchdogRegistry::add`] or the
+    /// [`builder`](Self::builder) and defaults to `0`.
+    #[must_use]
+    pub fn timeout_interval_ms(&self) -> T {
+        self.timeout_interval_ms
+    }
+
+    /// Returns the timestamp (ms) of the last feed of this watchdog node.
+    ///
+    /// The timestamp is updated by [`WatchdogRegistry::add`] and
+    /// [`WatchdogRegistry::feed`] and defaults to `0`.
+    #[must_use]
+    pub fn last_touched_timestamp_ms(&self) -> T {
+        self.last_touched_timestamp_ms
+    }
+
+    /// Returns the time elapsed since the last feed of this watchdog node.
+    ///
+    /// Computed with wrapping subtraction, so a timestamp wrap since the
+    /// last feed is handled correctly. Like
+    /// [`WatchdogRegistry::time_to_expiry`], it knows nothing about the
+    /// registry's [saturating](WatchdogRegistry::new_saturating) or
+    /// [tick](WatchdogRegistry::new_with_tick) mode.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    #[must_use]
+    pub fn elapsed(&self, now: T) -> T {
+        now.wrapping_sub(self.last_touched_timestamp_ms)
+    }
+
+    /// Returns the early-warning interval of this watchdog node in
+    /// milliseconds.
+    ///
+    /// The interval is set via [`WatchdogRegistry::set_warn_interval`] and
+    /// defaults to `0` (disabled).
+    #[must_use]
+    pub fn warn_interval_ms(&self) -> T {
+        self.warn_interval_ms
+    }
+
+    /// Returns the smallest remaining time (ms) this node ever had left
+    /// before expiring, as observed by [`WatchdogRegistry::check_all`].
+    ///
+    /// The mark is reset to `T::MAX` when the node is added to a registry
+    /// and only lowered by full scans — [`WatchdogRegistry::check`] stays on
+    /// its short-circuiting fast path and does not update it. A value of `0`
+    /// means the node was seen expired. Useful in soak tests to prove that
+    /// timeouts have headroom.
+    #[must_use]
+    pub fn min_margin_ms(&self) -> T {
+        self.min_margin_ms
+    }
+
+    /// Returns the minimum feed interval of this watchdog node in
+    /// milliseconds.
+    ///
+    /// The interval is set via [`WatchdogRegistry::set_window`] and defaults
+    /// to `0` (disabled).
+    #[must_use]
+    pub fn min_interval_ms(&self) -> T {
+        self.min_interval_ms
+    }
+
+    /// Returns the diagnostic name of this watchdog node.
+    ///
+    /// The name is set via [`WatchdogRegistry::set_name`] and defaults to
+    /// `None`. Only available with the `names` feature.
+    #[cfg(feature = "names")]
+    #[must_use]
+    pub fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    /// Returns the average interval (ms) between consecutive feeds since the
+    /// node was added.
+    ///
+    /// Useful to validate that a task feeds at the expected cadence. The
+    /// interval is measured with wrapping arithmetic, so a timestamp wrap
+    /// between two feeds is harmless; the running sum saturates. An
+    /// interval spanning a [`WatchdogRegistry::resume`] or
+    /// [`WatchdogRegistry::reset_all`] is measured from that call.
+    ///
+    /// # Returns
+    /// `None` until the node has been fed at least twice.
+    #[must_use]
+    pub fn avg_feed_gap_ms(&self) -> Option<T> {
+        (self.feed_count >= 2).then(|| self.accumulated_gap_ms.div_count(self.feed_count - 1))
+    }
+
+    /// Returns how many times this watchdog node has been caught expired.
+    ///
+    /// Incremented (saturating) by every full scan —
+    /// [`WatchdogRegistry::check_all`], [`WatchdogRegistry::check_force`]
+    /// and [`WatchdogRegistry::scan`] — that finds the node expired. The
+    /// counter survives feeding, [`WatchdogRegistry::clear_expired`] and
+    /// re-registration, so it accumulates across latch cycles; a node left
+    /// unfed is counted once per scan.
+    #[must_use]
+    pub fn expiry_count(&self) -> u32 {
+        self.expiry_count
+    }
+
+    /// Returns the number of consecutive [`WatchdogRegistry::check`] calls
+    /// that found no feed since the previous check.
+    ///
+    /// Only counted for nodes registered via
+    /// [`WatchdogRegistry::add_by_misses`]; always `0` otherwise.
+    #[must_use]
+    pub fn missed_checks(&self) -> u16 {
+        self.missed_checks.saturating_sub(1)
+    }
+
+    /// Returns `true` if this watchdog node is currently registered.
+    ///
+    /// The flag is set when the node is [added](WatchdogRegistry::add) and
+    /// cleared when it is removed by [`WatchdogRegistry::remove`],
+    /// [`WatchdogRegistry::remove_all`] or a one-shot expiry. Unlike a
+    /// non-null `next` pointer, it is also `true` for the tail node.
+    /// [`WatchdogRegistry::init`] does not touch the nodes, so it leaves
+    /// the flag stale.
+    #[must_use]
+    pub fn is_linked(&self) -> bool {
+        self.linked
+    }
+
+    /// Returns `true` if `self` and `other` are the same node.
+    ///
+    /// Nodes are compared by address, not by field values: two
+    /// default-initialized nodes are distinct watchdogs, which is also how
+    /// the registry tells nodes apart. `PartialEq` is deliberately not
+    /// implemented to avoid confusing the two notions.
+    #[must_use]
+    pub fn same_as(&self, other: &Self) -> bool {
+        ptr::eq(self, other)
+    }
+
+    /// Returns `true` if this watchdog node is one-shot.
+    ///
+    /// The flag is set via [`WatchdogRegistry::set_one_shot`] and defaults
+    /// to `false`.
+    #[must_use]
+    pub fn is_one_shot(&self) -> bool {
+        self.one_shot
+    }
+
+    /// Returns `true` if this watchdog node was registered with an absolute
+    /// deadline.
+    ///
+    /// See [`WatchdogRegistry::add_deadline`].
+    #[must_use]
+    pub fn is_deadline(&self) -> bool {
+        self.deadline
+    }
+
+    /// Returns `true` if monitoring of this watchdog node is paused.
+    ///
+    /// See [`WatchdogRegistry::pause`].
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns the group this watchdog node belongs to.
+    ///
+    /// The group is set via [`WatchdogRegistry::set_group`] and defaults to
+    /// `0`.
+    #[must_use]
+    pub fn group(&self) -> u8 {
+        self.group
+    }
+
+    /// Returns the user-assigned priority of this watchdog node.
+    ///
+    /// The priority is set via [`WatchdogRegistry::set_priority`] and
+    /// defaults to `0`.
+    #[must_use]
+    pub fn priority(&self) -> u8 {
+        self.priority
+    }
+
+    /// Record a feed at `now`, remembering the interval since the previous
+    /// one for the [early-feed check](WatchdogRegistry::set_window) and the
+    /// [average feed gap](Self::avg_feed_gap_ms).
+    ///
+    /// [Deadline](WatchdogRegistry::add_deadline) nodes are not heartbeats:
+    /// feeding them is a no-op.
+    fn touch(&mut self, now: T) {
+        if self.deadline {
+            return;
+        }
+
+        let gap = now.wrapping_sub(self.last_touched_timestamp_ms);
+        if self.fed {
+            self.feed_gap_ms = gap;
+        }
+        if self.feed_count > 0 {
+            self.accumulated_gap_ms = self.accumulated_gap_ms.saturating_add(gap);
+        }
+        self.feed_count = self.feed_count.saturating_add(1);
+        self.fed = true;
+        self.missed_checks = 0;
+        self.last_touched_timestamp_ms = now;
+    }
+}
+
+/// Prints the id, timeout and last-feed timestamp. The list link is omitted
+/// on purpose: raw addresses only add noise.
+impl<T: WrappingTime + fmt::Debug> fmt::Debug for WatchdogNodeT<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WatchdogNode")
+            .field("id", &self.id)
+            .field("timeout_interval_ms", &self.timeout_interval_ms)
+            .field("last_touched_timestamp_ms", &self.last_touched_timestamp_ms)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Builder for a [`WatchdogNodeT`], created by [`WatchdogNodeT::builder`].
+///
+/// Fields that are not set keep the same values as
+/// [`WatchdogNodeT::default`]. The builder never touches the list link —
+/// that is owned by the registry.
+#[derive(Clone, Copy)]
+pub struct WatchdogNodeBuilder<T: WrappingTime> {
+    id: u32,
+    timeout_ms: T,
+    warn_interval_ms: T,
+    one_shot: bool,
+    priority: u8,
+}
+
+impl<T: WrappingTime> Default for WatchdogNodeBuilder<T> {
+    fn default() -> Self {
+        WatchdogNodeT::builder()
+    }
+}
+
+impl<T: WrappingTime> WatchdogNodeBuilder<T> {
+    /// Sets the user-assigned identifier, see
+    /// [`WatchdogRegistry::assign_id`].
+    #[must_use]
+    pub const fn id(mut self, id: u32) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Sets the timeout interval in milliseconds, used by
+    /// [`WatchdogRegistryT::add_configured`].
+    #[must_use]
+    pub const fn timeout_ms(mut self, timeout_ms: T) -> Self {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Sets the early-warning interval in milliseconds, see
+    /// [`WatchdogRegistry::set_warn_interval`].
+    #[must_use]
+    pub const fn warn_interval_ms(mut self, warn_ms: T) -> Self {
+        self.warn_interval_ms = warn_ms;
+        self
+    }
+
+    /// Marks the node as one-shot, see [`WatchdogRegistry::set_one_shot`].
+    #[must_use]
+    pub const fn one_shot(mut self, one_shot: bool) -> Self {
+        self.one_shot = one_shot;
+        self
+    }
+
+    /// Sets the priority, see [`WatchdogRegistry::set_priority`].
+    #[must_use]
+    pub const fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Builds the unregistered node.
+    #[must_use]
+    pub const fn build(self) -> WatchdogNodeT<T> {
+        WatchdogNodeT {
+            timeout_interval_ms: self.timeout_ms,
+            last_touched_timestamp_ms: T::ZERO,
+            id: self.id,
+            warn_interval_ms: self.warn_interval_ms,
+            min_margin_ms: T::MAX,
+            min_interval_ms: T::ZERO,
+            feed_gap_ms: T::MAX,
+            accumulated_gap_ms: T::ZERO,
+            feed_count: 0,
+            expiry_count: 0,
+            one_shot: self.one_shot,
+            paused: false,
+            fed: false,
+            linked: false,
+            deadline: false,
+            priority: self.priority,
+            group: 0,
+            healthy_streak: u16::MAX,
+            recover_after: 0,
+            missed_checks: 0,
+            max_missed: 0,
+            #[cfg(feature = "names")]
+            name: None,
+            next: ptr::null_mut(),
+            prev: ptr::null_mut(),
+            _pin: PhantomPinned,
+        }
+    }
+}
+
+/// Builds a `[WatchdogNode; N]` table with pre-set ids and timeouts.
+///
+/// Each entry is an `(id, timeout_ms)` pair. The expansion only uses the
+/// `const` [`WatchdogNodeT::builder`], so the table can initialize a
+/// `static`. Link all nodes at once with [`WatchdogRegistryT::add_all`].
+///
+/// ```rust
+/// use mwdg::{watchdog_table, WatchdogRegistry};
+/// use core::pin::pin;
+///
+/// let mut registry = WatchdogRegistry::new();
+/// let mut table = pin!(watchdog_table![(1, 100), (2, 250)]);
+/// registry.add_all(table.as_mut(), 0);
+/// assert!(registry.iter_ids().eq([1, 2]));
+/// ```
+#[macro_export]
+macro_rules! watchdog_table {
+    ($(($id:expr, $timeout_ms:expr)),* $(,)?) => {
+        [$(
+            $crate::WatchdogNode::builder()
+                .id($id)
+                .timeout_ms($timeout_ms)
+                .build()
+        ),*]
+    };
+}
+
+#[cfg(feature = "defmt")]
+impl<T: WrappingTime + defmt::Format> defmt::Format for WatchdogNodeT<T> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "WatchdogNode {{ id: {=u32}, timeout_ms: {}, last_touched_ms: {} }}",
+            self.id,
+            self.timeout_interval_ms,
+            self.last_touched_timestamp_ms
+        );
+    }
+}
+
+/// A lightweight record of an expired watchdog, returned by
+/// [`WatchdogRegistryT::next_expiry_event`].
+///
+/// With the `defmt` feature enabled this type implements `defmt::Format`
+/// for compact logging on embedded targets.
+#[cfg_attr(
+    feature = "defmt",
+    doc = r"
+```rust
+fn assert_format<T: defmt::Format>() {}
+
+assert_format::<mwdg::ExpiryEvent<u32>>();
+assert_format::<mwdg::WatchdogNode>();
+```
+"
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ExpiryEvent<T: WrappingTime> {
+    /// User-assigned identifier of the expired node.
+    pub id: u32,
+    /// Timestamp (ms) at which the registry latched into the expired state.
+    pub expired_at_ms: T,
+    /// Diagnostic name of the expired node, see
+    /// [`WatchdogRegistryT::set_name`].
+    #[cfg(feature = "names")]
+    pub name: Option<&'static str>,
+}
+
+/// Errors reported by the fallible [`WatchdogRegistryT`] methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum WatchdogError {
+    /// The node is already linked into the registry.
+    AlreadyRegistered,
+    /// The registry already holds its
+    /// [maximum number of nodes](WatchdogRegistryT::with_capacity).
+    Full,
+    /// The timeout is zero, which would expire the node as soon as any time
+    /// passes.
+    ZeroTimeout,
+}
+
+impl fmt::Display for WatchdogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyRegistered => f.write_str("watchdog node is already registered"),
+            Self::Full => f.write_str("watchdog registry is full"),
+            Self::ZeroTimeout => f.write_str("watchdog timeout is zero"),
+        }
+    }
+}
+
+impl core::error::Error for WatchdogError {}
+
+/// Aggregate state of all watchdogs, returned by
+/// [`WatchdogRegistryT::health`].
+///
+/// Variants are ordered by severity, so `a.max(b)` combines two summaries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Health {
+    /// Every watchdog is within its warning interval.
+    Ok,
+    /// At least one watchdog is in its warning band, none has expired.
+    Warning,
+    /// At least one watchdog has expired.
+    Expired,
+}
+
+/// Plain status record of one watchdog, filled in by
+/// [`WatchdogRegistryT::snapshot`].
+///
+/// The struct is `#[repr(C)]` and contains no pointers, so a buffer of
+/// records can be sent out verbatim (e.g. via DMA over a serial link).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(C)]
+pub struct NodeStatus<T: WrappingTime> {
+    /// User-assigned identifier of the node.
+    pub id: u32,
+    /// Configured timeout interval in milliseconds.
+    pub timeout_ms: T,
+    /// Time left before the node expires, including the registry's
+    /// [grace period](WatchdogRegistryT::set_grace_ms); `0` once it counts
+    /// as expired.
+    pub remaining_ms: T,
+    /// Whether the node counted as expired at the snapshot time.
+    pub expired: bool,
+}
+
+impl<T: WrappingTime> Default for NodeStatus<T> {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            timeout_ms: T::ZERO,
+            remaining_ms: T::ZERO,
+            expired: false,
+        }
+    }
+}
+
+/// Result of a full scan, returned by [`WatchdogRegistryT::check_report`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CheckReport<T: WrappingTime> {
+    /// Whether the registry is latched after the scan, see
+    /// [`WatchdogRegistryT::check`].
+    pub expired: bool,
+    /// Id of the node that tripped the latch, see
+    /// [`WatchdogRegistryT::first_expired_id`].
+    pub first_expired_id: Option<u32>,
+    /// Number of nodes visited by the scan.
+    pub scanned: usize,
+    /// Timestamp at which the latch was first set, see
+    /// [`WatchdogRegistryT::expired_at`].
+    pub expired_at_ms: Option<T>,
+}
+
+/// Latch state of a registry captured by [`WatchdogRegistryT::checkpoint`].
+///
+/// The per-node timestamps live in a caller-owned buffer passed alongside,
+/// so taking a checkpoint never allocates. The record is opaque; it is only
+/// meant to be handed back to [`WatchdogRegistryT::restore`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RegistryCheckpoint<T: WrappingTime> {
+    /// Timestamp the checkpoint was taken at.
+    taken_at_ms: T,
+    /// Number of node timestamps written to the caller's buffer.
+    nodes: usize,
+    /// Saved copies of the registry's latch fields.
+    expired: bool,
+    expired_at_ms: T,
+    expired_id: u32,
+    scanned_at_ms: T,
+}
+
+impl<T: WrappingTime> RegistryCheckpoint<T> {
+    /// Returns the number of node timestamps captured in the buffer.
+    #[must_use]
+    pub fn nodes(&self) -> usize {
+        self.nodes
+    }
 }
 
 /// Owns the head of the intrusive linked list of registered watchdog nodes
@@ -112,17 +880,74 @@ impl WatchdogNode {
 /// let pinned = unsafe { Pin::new_unchecked(&mut node) };
 /// registry.add(pinned, 200, 0);
 /// ```
-pub struct WatchdogRegistry {
+// The mode flags are independent construction-time options, not a state
+// machine that an enum would describe better.
+#[allow(clippy::struct_excessive_bools)]
+pub struct WatchdogRegistryT<T: WrappingTime> {
     /// Head of the intrusive linked list of registered watchdogs.
-    head: *mut WatchdogNode,
+    head: *mut WatchdogNodeT<T>,
+    /// Last node of the list, so [`add`](Self::add) can append without
+    /// walking to the end. Null exactly when `head` is null.
+    tail: *mut WatchdogNodeT<T>,
     /// Whether any registered watchdog has expired. Once set, this flag is
-    /// never cleared (latching behaviour).
+    /// only cleared by [`init`](Self::init) or
+    /// [`clear_expired`](Self::clear_expired) (latching behaviour).
     expired: bool,
     /// Timestamp (ms) captured by [`check`](Self::check) at the moment it
-    /// first detected an expiration. [`next_expired`](Self::next_expired)
-    /// uses this snapshot instead of requiring the caller to pass `now`
-    /// again, so the two methods evaluate against the same point in time.
-    expired_at_ms: u32,
+    /// first detected an expiration. Reported by
+    /// [`expired_at`](Self::expired_at).
+    expired_at_ms: T,
+    /// Id of the node that tripped the latch. Reported by
+    /// [`first_expired_id`](Self::first_expired_id).
+    expired_id: u32,
+    /// Timestamp (ms) [`next_expired`](Self::next_expired) evaluates nodes
+    /// against, so it does not require the caller to pass `now` again.
+    /// Captured together with `expired_at_ms` when the registry latches and
+    /// refreshed by every [`check_force`](Self::check_force) while latched.
+    scanned_at_ms: T,
+    /// Optional hook invoked by [`check`](Self::check) with the id of the
+    /// node that trips the latch. Set via
+    /// [`set_on_expire`](Self::set_on_expire).
+    on_expire: Option<fn(id: u32)>,
+    /// Optional hook invoked by [`check`](Self::check) when the clock jumps
+    /// by more than half its range. Set via
+    /// [`set_on_time_anomaly`](Self::set_on_time_anomaly).
+    on_time_anomaly: Option<fn(prev: T, now: T)>,
+    /// `now` of the previous [`check`](Self::check) call, `None` before the
+    /// first one.
+    prev_check_ms: Option<T>,
+    /// Grace period (ms) added to every node's timeout when deciding
+    /// expiry. Set via [`set_grace_ms`](Self::set_grace_ms).
+    grace_ms: T,
+    /// Compute elapsed time with saturating instead of wrapping
+    /// subtraction. Selected at construction via
+    /// [`new_saturating`](Self::new_saturating).
+    saturating: bool,
+    /// Treat an elapsed time *equal* to the timeout as expired. Selected at
+    /// construction via [`new_inclusive`](Self::new_inclusive).
+    inclusive: bool,
+    /// Re-evaluate the expired state on every scan instead of latching it.
+    /// Selected at construction via
+    /// [`new_non_latching`](Self::new_non_latching).
+    non_latching: bool,
+    /// Move nodes fed through the registry to the tail of the list. Set via
+    /// [`set_feed_to_tail`](Self::set_feed_to_tail).
+    feed_to_tail: bool,
+    /// Milliseconds per timestamp tick. Selected at construction via
+    /// [`new_with_tick`](Self::new_with_tick); `1` for the other
+    /// constructors.
+    tick_scale_ms: u32,
+    /// Maximum number of registered nodes. Selected at construction via
+    /// [`with_capacity`](Self::with_capacity); unbounded (`usize::MAX`) for
+    /// the other constructors.
+    max_nodes: usize,
+    /// Number of nodes in the list, kept so that the capacity check does
+    /// not have to walk it.
+    node_count: usize,
+    /// Bumped whenever a node may have left the list or been relinked, so
+    /// a [`CursorT`] issued before can no longer be trusted without
+    /// re-validating it.
+    generation: usize,
 }
 
 // SAFETY: `WatchdogRegistry` owns an intrusive linked list of `WatchdogNode`
@@ -131,15 +956,15 @@ pub struct WatchdogRegistry {
 // is the caller's responsibility (e.g. via `Mutex<WatchdogRegistry>`).
 // The raw pointers are an implementation detail; they do not alias mutable
 // references in other threads.
-unsafe impl Send for WatchdogRegistry {}
+unsafe impl<T: WrappingTime> Send for WatchdogRegistryT<T> {}
 
-impl Default for WatchdogRegistry {
+impl<T: WrappingTime> Default for WatchdogRegistryT<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl WatchdogRegistry {
+impl<T: WrappingTime> WatchdogRegistryT<T> {
     /// Create a new, empty watchdog registry.
     ///
     /// No watchdogs are registered and the expiration state is clear.
@@ -147,684 +972,7064 @@ impl WatchdogRegistry {
     pub const fn new() -> Self {
         Self {
             head: ptr::null_mut(),
+            tail: ptr::null_mut(),
             expired: false,
-            expired_at_ms: 0,
+            expired_at_ms: T::ZERO,
+            expired_id: 0,
+            scanned_at_ms: T::ZERO,
+            on_expire: None,
+            on_time_anomaly: None,
+            prev_check_ms: None,
+            grace_ms: T::ZERO,
+            saturating: false,
+            inclusive: false,
+            non_latching: false,
+            feed_to_tail: false,
+            tick_scale_ms: 1,
+            max_nodes: usize::MAX,
+            node_count: 0,
+            generation: 0,
         }
     }
 
-    /// Re-initialize the registry, resetting it to the same state as
-    /// [`new`](Self::new).
+    /// Create a new, empty watchdog registry that computes elapsed time with
+    /// saturating subtraction.
     ///
-    /// Any previously registered nodes are effectively unlinked from the
-    /// registry's perspective (their individual `next` pointers are **not**
-    /// cleared — the caller is responsible for dropping or re-initializing
-    /// them).
-    pub fn init(&mut self) {
-        self.head = ptr::null_mut();
-        self.expired = false;
-        self.expired_at_ms = 0;
+    /// The default [`new`](Self::new) registry uses wrapping arithmetic,
+    /// which is correct for monotonic tick counters that overflow back to
+    /// zero. A clock that can jump *backwards* (e.g. an RTC-derived counter
+    /// corrected by NTP) instead makes the wrapped elapsed time huge and
+    /// triggers a false expiration. In saturating mode a timestamp ahead of
+    /// `now` yields an elapsed time of zero, so backward jumps are treated
+    /// as "no time passed". The trade-off is that such a registry does not
+    /// survive the clock overflowing.
+    ///
+    /// Wrapping registries already treat a timestamp up to half the range
+    /// ahead of `now` as fed "in the future", which covers small backward
+    /// jumps and nodes fed between reading the clock and checking. This
+    /// mode is still needed for clocks that can jump back by more than
+    /// that, e.g. a 32-bit millisecond counter reset after several weeks of
+    /// uptime.
+    #[must_use]
+    pub const fn new_saturating() -> Self {
+        Self {
+            saturating: true,
+            ..Self::new()
+        }
     }
 
-    /// Returns `true` if the registry has latched into the expired state.
+    /// Create a new, empty watchdog registry whose timestamps count ticks of
+    /// `scale_ms` milliseconds.
     ///
-    /// This is a cheap field read — no list traversal is performed.
-    /// The companion `mwdg-ffi` crate uses this for an early-return
-    /// optimisation in `mwdg_check` that avoids entering the critical
-    /// section when the registry is already known to be expired.
+    /// Every `now` passed to the registry (and to [`feed`](Self::feed)) is
+    /// then a native tick count, while timeouts, warning intervals and the
+    /// grace period stay in milliseconds. The scans convert each elapsed
+    /// tick count to milliseconds (saturating, so a huge count cannot wrap)
+    /// before comparing, and report remaining times in milliseconds. A
+    /// `scale_ms` of `1` (or `0`, which is treated as `1`) is identical to
+    /// [`new`](Self::new).
+    ///
+    /// Per-node feed statistics — the [feed window](Self::set_window) and
+    /// [`avg_feed_gap_ms`](WatchdogNodeT::avg_feed_gap_ms) — as well as the
+    /// static [`time_to_expiry`](Self::time_to_expiry) do not know the
+    /// registry and therefore stay in native ticks.
+    ///
+    /// # Parameters
+    /// - `scale_ms`: length of one timestamp tick in milliseconds.
     #[must_use]
-    pub fn is_expired(&self) -> bool {
-        self.expired
+    pub const fn new_with_tick(scale_ms: u32) -> Self {
+        Self {
+            tick_scale_ms: if scale_ms == 0 { 1 } else { scale_ms },
+            ..Self::new()
+        }
     }
 
-    /// Register a watchdog node with the given timeout.
-    ///
-    /// The node is prepended to the registry's internal linked list. Its
-    /// `last_touched_timestamp_ms` is set to `now` and its timeout is set to
-    /// `timeout_ms`.
+    /// Create a new, empty watchdog registry holding at most `max_nodes`
+    /// watchdogs.
     ///
-    /// If the node is already present in the list (detected by raw pointer
-    /// comparison), the call acts as a combined
-    /// [`feed`](Self::feed) + timeout update — the node is **not** added a
-    /// second time.
+    /// Once the list reaches the cap, registering another node is refused
+    /// ([`try_add`](Self::try_add) reports [`WatchdogError::Full`]), which
+    /// bounds the worst-case duration of [`check`](Self::check) even if
+    /// buggy code keeps registering nodes. Re-adding an already registered
+    /// node is always allowed. [`merge`](Self::merge) refuses to exceed the
+    /// cap as well.
     ///
     /// # Parameters
-    /// - `node`: a pinned mutable reference to the watchdog node.
-    /// - `timeout_ms`: timeout interval in milliseconds.
-    /// - `now`: the current timestamp in milliseconds.
-    pub fn add(&mut self, node: Pin<&mut WatchdogNode>, timeout_ms: u32, now: u32) {
-        // Obtain a raw pointer to the node. We need this for list operations.
-        // SAFETY: We are not moving the node — only reading its address and
-        // writing to its fields through the raw pointer. The Pin guarantee
-        // ensures the caller will not move the node after this call.
-        let node_ptr: *mut WatchdogNode = unsafe { &raw mut *node.get_unchecked_mut() };
+    /// - `max_nodes`: maximum number of registered nodes.
+    #[must_use]
+    pub const fn with_capacity(max_nodes: usize) -> Self {
+        Self {
+            max_nodes,
+            ..Self::new()
+        }
+    }
 
-        // Check if the node is already in the list.
+    /// Create a new, empty watchdog registry with an inclusive deadline.
+    ///
+    /// The default [`new`](Self::new) registry treats a node fed exactly at
+    /// its deadline (elapsed time equal to the timeout) as healthy, i.e. it
+    /// expires on `elapsed > timeout`. An inclusive registry expires on
+    /// `elapsed >= timeout` instead, for specifications that consider such a
+    /// node late. [`check`](Self::check), [`next_expired`](Self::next_expired)
+    /// and the other scans all use the same boundary.
+    #[must_use]
+    pub const fn new_inclusive() -> Self {
+        Self {
+            inclusive: true,
+            ..Self::new()
+        }
+    }
+
+    /// Create a new, empty watchdog registry whose expired state is not
+    /// sticky.
+    ///
+    /// The default latch is what makes [`check`](Self::check) safe for
+    /// gating a hardware watchdog: a fault stays reported even if the task
+    /// recovers. A non-latching registry suits a monitoring dashboard
+    /// instead: every `check` (and every other full or group scan)
+    /// recomputes the expired state, so the registry reads healthy again
+    /// once all tasks resume feeding. While expired,
+    /// [`next_expired`](Self::next_expired) and
+    /// [`expired_at`](Self::expired_at) refer to the `now` of the last scan,
+    /// and the [on-expire hook](Self::set_on_expire) fires on every scan
+    /// that finds an expired node.
+    ///
+    /// Do not use this mode to decide whether to kick a hardware watchdog.
+    #[must_use]
+    pub const fn new_non_latching() -> Self {
+        Self {
+            non_latching: true,
+            ..Self::new()
+        }
+    }
+
+    /// Re-initialize the registry, resetting it to the same state as
+    /// [`new`](Self::new). The elapsed-time mode, deadline boundary, tick
+    /// scale, capacity and latching mode chosen at construction are
+    /// preserved.
+    ///
+    /// Any previously registered nodes are effectively unlinked from the
+    /// registry's perspective (their individual `next` pointers and
+    /// [linked](WatchdogNodeT::is_linked) flags are **not** cleared — the
+    /// caller is responsible for dropping or re-initializing them).
+    pub fn init(&mut self) {
+        self.head = ptr::null_mut();
+        self.tail = ptr::null_mut();
+        self.node_count = 0;
+        self.generation = self.generation.wrapping_add(1);
+        self.expired = false;
+        self.expired_at_ms = T::ZERO;
+        self.expired_id = 0;
+        self.scanned_at_ms = T::ZERO;
+        self.on_expire = None;
+        self.on_time_anomaly = None;
+        self.prev_check_ms = None;
+        self.grace_ms = T::ZERO;
+        self.feed_to_tail = false;
+    }
+
+    /// Register a hook invoked from [`check`](Self::check) the moment an
+    /// expired node is found.
+    ///
+    /// The hook receives the id of the first expired node and is called
+    /// right before the registry latches, so it fires exactly once per
+    /// latch cycle. It is additionally called once for every expired
+    /// [one-shot](Self::set_one_shot) node as that node is unregistered.
+    /// Registering a new hook replaces the previous one.
+    ///
+    /// # Re-entrancy
+    ///
+    /// The hook runs inside `check`, i.e. typically inside the caller's
+    /// critical section. It must be short and must **not** call back into
+    /// the registry.
+    ///
+    /// # Parameters
+    /// - `cb`: plain function pointer receiving the expired node's id.
+    pub fn set_on_expire(&mut self, cb: fn(id: u32)) {
+        self.on_expire = Some(cb);
+    }
+
+    /// Register a hook invoked from [`check`](Self::check) when the clock
+    /// appears to glitch.
+    ///
+    /// Wrapping arithmetic is only meaningful while consecutive timestamps
+    /// are less than half the timestamp range apart. If `now` differs from
+    /// the previous `check`'s `now` by more (most commonly, a clock that
+    /// jumped backwards), healthy/expired decisions may silently flip; the
+    /// hook receives both timestamps so the glitch can be logged. The same
+    /// re-entrancy rules as for the [expiry hook](Self::set_on_expire)
+    /// apply. Registering a new hook replaces the previous one.
+    ///
+    /// # Parameters
+    /// - `cb`: plain function pointer receiving the previous and current
+    ///   `now`.
+    pub fn set_on_time_anomaly(&mut self, cb: fn(prev: T, now: T)) {
+        self.on_time_anomaly = Some(cb);
+    }
+
+    /// Set a grace period applied uniformly to every watchdog's timeout.
+    ///
+    /// While non-zero, a node only counts as expired once its elapsed time
+    /// exceeds `timeout + grace_ms` (saturating). This gives tasks a
+    /// temporary extension while they reach steady state during boot,
+    /// without touching each node. Setting `0` restores normal behaviour.
+    /// [`check`](Self::check), [`next_expired`](Self::next_expired) and the
+    /// other scans all apply the same effective timeout.
+    ///
+    /// # Parameters
+    /// - `grace_ms`: grace period in milliseconds.
+    pub fn set_grace_ms(&mut self, grace_ms: T) {
+        self.grace_ms = grace_ms;
+    }
+
+    /// Keep the list ordered from least to most recently fed.
+    ///
+    /// While enabled, [`feed_checked`](Self::feed_checked) and
+    /// [`feed_by_id`](Self::feed_by_id) move the fed node to the tail of the
+    /// list, so the stalest node bubbles up to the head and
+    /// [`next_expired`](Self::next_expired) reports it first instead of
+    /// whichever node happened to register first. The static
+    /// [`feed`](Self::feed) has no access to the list and leaves the order
+    /// alone.
+    ///
+    /// The relink itself is `O(1)`, but both feeds already walk the list to
+    /// find the node, so a feed stays `O(n)`. Disabled by default and reset
+    /// by [`init`](Self::init).
+    ///
+    /// # Parameters
+    /// - `enabled`: `true` to move fed nodes to the tail.
+    pub fn set_feed_to_tail(&mut self, enabled: bool) {
+        self.feed_to_tail = enabled;
+    }
+
+    /// Returns `true` if the registry has latched into the expired state.
+    ///
+    /// This is a cheap field read — no list traversal is performed.
+    /// The companion `mwdg-ffi` crate uses this for an early-return
+    /// optimisation in `mwdg_check` that avoids entering the critical
+    /// section when the registry is already known to be expired.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.expired
+    }
+
+    /// Returns the timestamp (ms) at which an expiration was first detected.
+    ///
+    /// `None` while the registry is not latched, so a stale zero can never
+    /// be mistaken for a real detection time. Useful for correlating a
+    /// latched fault with other logs.
+    #[must_use]
+    pub fn expired_at(&self) -> Option<T> {
+        self.expired.then_some(self.expired_at_ms)
+    }
+
+    /// Returns how long (ms) the registry has been latched at `now`.
+    ///
+    /// Measured from [`expired_at`](Self::expired_at) with the registry's
+    /// elapsed-time arithmetic (wrapping by default, so a timestamp wrap
+    /// since the detection is harmless), which lets a recovery policy
+    /// escalate from logging to a hard reset once the fault persists.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// `None` while the registry is not latched.
+    #[must_use]
+    pub fn time_since_expiry(&self, now: T) -> Option<T> {
+        self.expired.then(|| self.elapsed(now, self.expired_at_ms))
+    }
+
+    /// Returns the id of the node that tripped the latch.
+    ///
+    /// Recorded when the registry latches, so the common single-fault case
+    /// is answered without iterating with
+    /// [`next_expired`](Self::next_expired). `None` while the registry is
+    /// not latched.
+    #[must_use]
+    pub fn first_expired_id(&self) -> Option<u32> {
+        self.expired.then_some(self.expired_id)
+    }
+
+    /// Clear the latched expired state while keeping all registered nodes.
+    ///
+    /// After this call the registry behaves as if no expiration had ever
+    /// been detected: [`check`](Self::check) scans the list again and
+    /// [`next_expired`](Self::next_expired) returns `None` until the next
+    /// expiration latches.
+    ///
+    /// # Warning
+    ///
+    /// This defeats the latching guarantee that makes [`check`](Self::check)
+    /// safe to use for gating a hardware watchdog: a stuck task that
+    /// happens to be fed again before the next scan will no longer be
+    /// reported. Only call this after the caller has confirmed that the
+    /// system recovered from the fault.
+    pub fn clear_expired(&mut self) {
+        #[cfg(feature = "log")]
+        log::debug!("watchdog latch cleared");
+        self.expired = false;
+        self.expired_at_ms = T::ZERO;
+        self.expired_id = 0;
+        self.scanned_at_ms = T::ZERO;
+    }
+
+    /// Latch the registry as if `check` had detected node `id` expired at
+    /// `expired_at_ms`.
+    ///
+    /// The counterpart of observing the latch through
+    /// [`expired_at`](Self::expired_at) and
+    /// [`first_expired_id`](Self::first_expired_id), for adapters that
+    /// carry the latch across a boundary, e.g. a warm reset with retained
+    /// RAM. Afterwards the accessors report the seeded values, and
+    /// [`next_expired`](Self::next_expired) evaluates the nodes against
+    /// `expired_at_ms`. The [on-expire hook](Self::set_on_expire) is not
+    /// invoked. A registry that has already latched keeps its original
+    /// detection, like a real `check` would.
+    ///
+    /// # Parameters
+    /// - `expired_at_ms`: timestamp of the detection.
+    /// - `id`: id of the node that tripped the latch.
+    pub fn seed_latch(&mut self, expired_at_ms: T, id: u32) {
+        if self.expired {
+            return;
+        }
+
+        self.expired = true;
+        self.expired_at_ms = expired_at_ms;
+        self.expired_id = id;
+        self.scanned_at_ms = expired_at_ms;
+    }
+
+    /// Register a watchdog node with the given timeout.
+    ///
+    /// The node is appended to the registry's internal linked list, so
+    /// iteration order matches registration order. Its
+    /// `last_touched_timestamp_ms` is set to `now` and its timeout is set to
+    /// `timeout_ms`.
+    ///
+    /// If the node is already present in the list (detected by raw pointer
+    /// comparison), the call acts as a combined
+    /// [`feed`](Self::feed) + timeout update — the node is **not** added a
+    /// second time. A new node that would exceed the registry's
+    /// [capacity](Self::with_capacity) is silently not registered; use
+    /// [`try_add`](Self::try_add) to detect that.
+    ///
+    /// Any `timeout_ms` up to `T::MAX` is valid. A zero timeout is accepted
+    /// but makes the node expire as soon as any time passes since its last
+    /// feed — usually a forgotten initialization, which
+    /// [`try_add`](Self::try_add) rejects. Offsets such as the
+    /// [grace period](Self::set_grace_ms) are added with saturation, so the
+    /// effective timeout never wraps around to a small value; a node with a
+    /// `T::MAX` timeout can only expire once the elapsed time itself spans
+    /// the whole timestamp range.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `timeout_ms`: timeout interval in milliseconds.
+    /// - `now`: the current timestamp in milliseconds.
+    pub fn add(&mut self, node: Pin<&mut WatchdogNodeT<T>>, timeout_ms: T, now: T) {
+        self.add_reporting(node, timeout_ms, now);
+    }
+
+    /// Register a watchdog node, reporting whether it was newly inserted.
+    ///
+    /// Behaves exactly like [`add`](Self::add), but lets init code assert
+    /// that each node registers exactly once.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `timeout_ms`: timeout interval in milliseconds.
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// `true` if the node was appended to the list, `false` if it was
+    /// already registered and the call acted as a feed + timeout update, or
+    /// if the registry is at its [capacity](Self::with_capacity).
+    pub fn add_reporting(
+        &mut self,
+        node: Pin<&mut WatchdogNodeT<T>>,
+        timeout_ms: T,
+        now: T,
+    ) -> bool {
+        self.link(node, timeout_ms, now).unwrap_or(false)
+    }
+
+    /// Shared implementation of the `add` family.
+    ///
+    /// Returns `Ok(true)` if the node was appended, `Ok(false)` if it was
+    /// already registered and only refreshed, and
+    /// [`WatchdogError::Full`] (leaving the node untouched) if a new node
+    /// would exceed the [capacity](Self::with_capacity).
+    fn link(
+        &mut self,
+        node: Pin<&mut WatchdogNodeT<T>>,
+        timeout_ms: T,
+        now: T,
+    ) -> Result<bool, WatchdogError> {
+        // Obtain a raw pointer to the node. We need this for list operations.
+        // SAFETY: We are not moving the node — only reading its address and
+        // writing to its fields through the raw pointer. The Pin guarantee
+        // ensures the caller will not move the node after this call.
+        let node_ptr: *mut WatchdogNodeT<T> = unsafe { &raw mut *node.get_unchecked_mut() };
+
+        // Check if the node is already in the list.
+        if self.contains_ptr(node_ptr) {
+            // Node is already registered — update timestamp and timeout.
+            // SAFETY: `node_ptr` points to a valid `WatchdogNode` that
+            // is pinned and alive (the caller holds a Pin<&mut> to it).
+            unsafe {
+                (*node_ptr).last_touched_timestamp_ms = now;
+                (*node_ptr).timeout_interval_ms = timeout_ms;
+                (*node_ptr).feed_gap_ms = T::MAX;
+                (*node_ptr).fed = false;
+                (*node_ptr).deadline = false;
+                (*node_ptr).missed_checks = 0;
+                (*node_ptr).max_missed = 0;
+            }
+            return Ok(false);
+        }
+
+        if self.node_count == self.max_nodes {
+            return Err(WatchdogError::Full);
+        }
+
+        // Node is not in the list — initialize fields and append at the
+        // tail.
+        // SAFETY: `node_ptr` points to a valid, pinned `WatchdogNode`.
+        unsafe {
+            Self::init_linked(node_ptr, timeout_ms, now);
+        }
+        if self.tail.is_null() {
+            self.head = node_ptr;
+        } else {
+            // SAFETY: a non-null `tail` is the last valid node in the list
+            // and `node_ptr` was just initialized above.
+            unsafe {
+                (*self.tail).next = node_ptr;
+                (*node_ptr).prev = self.tail;
+            }
+        }
+        self.tail = node_ptr;
+        self.node_count += 1;
+
+        #[cfg(feature = "log")]
+        // SAFETY: `node_ptr` was just linked and is valid.
+        log::debug!(
+            "watchdog {} registered with timeout {timeout_ms:?}",
+            unsafe { (*node_ptr).id }
+        );
+
+        Ok(true)
+    }
+
+    /// Initialize the runtime fields of a node that is about to be linked.
+    ///
+    /// The margin mark, the feed gap and the healthy streak are reset here
+    /// rather than relying on the initializer, since a zero-initialized C
+    /// node would start at `0`. The caller links the node afterwards.
+    ///
+    /// # Safety
+    /// `node_ptr` must point to a valid, pinned node that is not linked
+    /// into any list.
+    unsafe fn init_linked(node_ptr: *mut WatchdogNodeT<T>, timeout_ms: T, now: T) {
+        // SAFETY: guaranteed by the caller.
+        unsafe {
+            (*node_ptr).last_touched_timestamp_ms = now;
+            (*node_ptr).timeout_interval_ms = timeout_ms;
+            (*node_ptr).min_margin_ms = T::MAX;
+            (*node_ptr).feed_gap_ms = T::MAX;
+            (*node_ptr).accumulated_gap_ms = T::ZERO;
+            (*node_ptr).feed_count = 0;
+            (*node_ptr).fed = false;
+            (*node_ptr).deadline = false;
+            (*node_ptr).missed_checks = 0;
+            (*node_ptr).max_missed = 0;
+            (*node_ptr).healthy_streak = u16::MAX;
+            (*node_ptr).linked = true;
+            (*node_ptr).next = ptr::null_mut();
+            (*node_ptr).prev = ptr::null_mut();
+        }
+    }
+
+    /// Replace a registered node with another one at the same list position.
+    ///
+    /// `new` is initialized exactly like a fresh [`add`](Self::add) and
+    /// spliced into `old`'s slot, so iteration order (and with it which node
+    /// [`check`](Self::check) reports first) is preserved. `old` is unlinked
+    /// and its `next` pointer cleared. If `new` is already registered it is
+    /// moved from its current position. Replacing a node with itself only
+    /// refreshes its timeout and timestamp.
+    ///
+    /// # Parameters
+    /// - `old`: a pinned mutable reference to the registered node to replace.
+    /// - `new`: a pinned mutable reference to the replacement node.
+    /// - `timeout_ms`: timeout interval of `new` in milliseconds.
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// `true` if `old` was found and replaced, `false` if it is not
+    /// registered (nothing is changed).
+    pub fn replace(
+        &mut self,
+        old: Pin<&mut WatchdogNodeT<T>>,
+        mut new: Pin<&mut WatchdogNodeT<T>>,
+        timeout_ms: T,
+        now: T,
+    ) -> bool {
+        // SAFETY: We only read the addresses; we do not move the nodes.
+        let old_ptr: *mut WatchdogNodeT<T> = unsafe { &raw mut *old.get_unchecked_mut() };
+        let new_ptr: *mut WatchdogNodeT<T> = unsafe { &raw mut *new.as_mut().get_unchecked_mut() };
+
+        if !self.contains_ptr(old_ptr) {
+            return false;
+        }
+        if old_ptr == new_ptr {
+            self.add(new, timeout_ms, now);
+            return true;
+        }
+        self.remove(new);
+
+        // SAFETY: `old` is known to be linked into this list, so its `prev`
+        // is its predecessor (or null for the head).
+        let prev = unsafe { (*old_ptr).prev };
+
+        // SAFETY: `old_ptr` is linked with predecessor `prev` (or null for the
+        // head) and `new_ptr` is a valid, pinned node that is not linked.
+        unsafe {
+            let next = (*old_ptr).next;
+            Self::init_linked(new_ptr, timeout_ms, now);
+            (*new_ptr).next = next;
+            (*new_ptr).prev = prev;
+            if prev.is_null() {
+                self.head = new_ptr;
+            } else {
+                (*prev).next = new_ptr;
+            }
+            if next.is_null() {
+                self.tail = new_ptr;
+            } else {
+                (*next).prev = new_ptr;
+            }
+            (*old_ptr).next = ptr::null_mut();
+            (*old_ptr).prev = ptr::null_mut();
+            (*old_ptr).linked = false;
+        }
+        self.generation = self.generation.wrapping_add(1);
+
+        true
+    }
+
+    /// Register a watchdog node, rejecting a duplicate registration.
+    ///
+    /// Strict counterpart of [`add`](Self::add): instead of treating an
+    /// already registered node as a feed + timeout update, the call fails
+    /// and leaves the node untouched. This surfaces bugs where the same node
+    /// is registered twice by mistake.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `timeout_ms`: timeout interval in milliseconds.
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Errors
+    /// - [`WatchdogError::AlreadyRegistered`] if the node is already in the
+    ///   list.
+    /// - [`WatchdogError::Full`] if the registry is at its
+    ///   [capacity](Self::with_capacity).
+    /// - [`WatchdogError::ZeroTimeout`] if `timeout_ms` is zero.
+    pub fn try_add(
+        &mut self,
+        node: Pin<&mut WatchdogNodeT<T>>,
+        timeout_ms: T,
+        now: T,
+    ) -> Result<(), WatchdogError> {
+        if timeout_ms == T::ZERO {
+            return Err(WatchdogError::ZeroTimeout);
+        }
+        if self.contains_ptr(&raw const *node) {
+            return Err(WatchdogError::AlreadyRegistered);
+        }
+
+        self.link(node, timeout_ms, now).map(|_| ())
+    }
+
+    /// Register a watchdog node using the timeout already stored in it.
+    ///
+    /// Equivalent to [`add`](Self::add) with the node's current
+    /// [`timeout_interval_ms`](WatchdogNodeT::timeout_interval_ms), so a
+    /// node configured through [`WatchdogNodeT::builder`] only needs the
+    /// current time.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `now`: the current timestamp in milliseconds.
+    pub fn add_configured(&mut self, node: Pin<&mut WatchdogNodeT<T>>, now: T) {
+        let timeout_ms = node.timeout_interval_ms;
+        self.add(node, timeout_ms, now);
+    }
+
+    /// Register a watchdog node that must complete by an absolute deadline.
+    ///
+    /// Instead of a periodic heartbeat, the node is flagged by
+    /// [`check`](Self::check) once `now` passes `deadline_ms`, using the same
+    /// wrap-aware comparison as interval timeouts (the deadline must lie
+    /// within half the timestamp range of `now`). Feeding the node does not
+    /// move the deadline; once the guarded work is done, unregister it via
+    /// [`complete`](Self::complete). A deadline that is already in the past
+    /// expires on the next `check`. A later [`add`](Self::add) turns the node
+    /// back into a regular interval watchdog. A node rejected because the
+    /// registry is at its [capacity](Self::with_capacity) is left untouched.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `deadline_ms`: absolute timestamp the work must complete by.
+    /// - `now`: the current timestamp in milliseconds.
+    pub fn add_deadline(&mut self, mut node: Pin<&mut WatchdogNodeT<T>>, deadline_ms: T, now: T) {
+        let remaining = deadline_ms.wrapping_sub(now);
+        // A deadline in the past is stored as already elapsed, so the
+        // comparison below the half-range cannot mistake it for one far in
+        // the future.
+        let (start, timeout_ms) = if remaining > T::HALF_RANGE {
+            (deadline_ms, T::ZERO)
+        } else {
+            (now, remaining)
+        };
+
+        self.add(node.as_mut(), timeout_ms, start);
+        if !node.linked {
+            return;
+        }
+        // SAFETY: Writing to a field; not moving the node.
+        unsafe {
+            node.get_unchecked_mut().deadline = true;
+        }
+    }
+
+    /// Mark the work guarded by a [deadline](Self::add_deadline) node as
+    /// done, unregistering the node.
+    ///
+    /// Equivalent to [`remove`](Self::remove); it only documents intent at
+    /// the call site.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    pub fn complete(&mut self, node: Pin<&mut WatchdogNodeT<T>>) {
+        self.remove(node);
+    }
+
+    /// Register a watchdog node that expires after a number of missed
+    /// checks rather than a time interval.
+    ///
+    /// Every [`check`](Self::check) counts as one supervisor tick. A tick
+    /// is [missed](WatchdogNodeT::missed_checks) if the node was not fed
+    /// since the previous one (registering counts as a feed), and any feed
+    /// resets the count. Once `max_missed` consecutive ticks are missed,
+    /// the node is expired. This suits tasks with a jittery cadence that
+    /// still reliably feed at least once per supervisor tick. A budget of
+    /// `0` is treated as `1`, i.e. the first missed tick expires the node.
+    ///
+    /// If the registry is at its [capacity](Self::with_capacity), the node
+    /// is rejected like by [`add`](Self::add) and left untouched.
+    ///
+    /// Elapsed time is ignored for such a node. The other scans
+    /// ([`check_all`](Self::check_all), [`scan`](Self::scan),
+    /// [`check_group`](Self::check_group), ...) evaluate the budget
+    /// without counting a tick. A later [`add`](Self::add) turns the node
+    /// back into a regular interval watchdog.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `max_missed`: consecutive missed ticks before expiry.
+    /// - `now`: the current timestamp in milliseconds.
+    pub fn add_by_misses(&mut self, mut node: Pin<&mut WatchdogNodeT<T>>, max_missed: u16, now: T) {
+        self.add(node.as_mut(), T::MAX, now);
+        if !node.linked {
+            return;
+        }
+        // SAFETY: Writing to a field; not moving the node.
+        unsafe {
+            node.get_unchecked_mut().max_missed = max_missed.max(1);
+        }
+    }
+
+    /// Register every node of a pinned slice using the timeouts already
+    /// stored in them.
+    ///
+    /// Equivalent to calling [`add_configured`](Self::add_configured) for
+    /// each element in order. Intended for tables built with
+    /// [`watchdog_table!`].
+    ///
+    /// # Parameters
+    /// - `nodes`: a pinned mutable slice of watchdog nodes.
+    /// - `now`: the current timestamp in milliseconds.
+    pub fn add_all(&mut self, nodes: Pin<&mut [WatchdogNodeT<T>]>, now: T) {
+        // SAFETY: Pinning is structural for slice elements: the slice is
+        // pinned, so none of its elements can move either. We never move
+        // them out, and re-pin each one before handing it to `add`.
+        let nodes = unsafe { nodes.get_unchecked_mut() };
+        for node in nodes {
+            // SAFETY: see above — `node` lives inside the pinned slice.
+            self.add_configured(unsafe { Pin::new_unchecked(node) }, now);
+        }
+    }
+
+    /// Assign ids and timeouts to the nodes of a pinned slice and register
+    /// them.
+    ///
+    /// The `i`-th element of `specs` is an `(id, timeout_ms)` pair applied
+    /// to the `i`-th node before it is [added](Self::add). Pairing stops at
+    /// the shorter of the two slices, so a backing array larger than needed
+    /// is fine. This removes the per-node pinning boilerplate when a set of
+    /// watchdogs is known up front, e.g. in tests.
+    ///
+    /// Only available with the `std` feature (and in this crate's tests).
+    ///
+    /// # Parameters
+    /// - `nodes`: a pinned mutable slice of watchdog nodes.
+    /// - `specs`: `(id, timeout_ms)` for each node to register.
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// The number of nodes registered, i.e. `min(nodes.len(), specs.len())`.
+    #[cfg(any(test, feature = "std"))]
+    pub fn register_from(
+        &mut self,
+        nodes: Pin<&mut [WatchdogNodeT<T>]>,
+        specs: &[(u32, T)],
+        now: T,
+    ) -> usize {
+        // SAFETY: Pinning is structural for slice elements, see `add_all`.
+        let nodes = unsafe { nodes.get_unchecked_mut() };
+        let mut count = 0;
+        for (node, &(id, timeout_ms)) in nodes.iter_mut().zip(specs) {
+            // SAFETY: `node` lives inside the pinned slice and is never
+            // moved.
+            let mut node = unsafe { Pin::new_unchecked(node) };
+            Self::assign_id(node.as_mut(), id);
+            self.add(node, timeout_ms, now);
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Register a watchdog node and return a guard that removes it again
+    /// when dropped.
+    ///
+    /// Behaves like [`add`](Self::add), but ties the registration to the
+    /// returned [`WatchdogGuard`] instead of relying on a matching
+    /// [`remove`](Self::remove) call. See [`WatchdogGuard`] for the
+    /// borrowing rules that keep the guard from outliving either side.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `timeout_ms`: timeout interval in milliseconds.
+    /// - `now`: the current timestamp in milliseconds.
+    pub fn register<'r>(
+        &'r mut self,
+        mut node: Pin<&'r mut WatchdogNodeT<T>>,
+        timeout_ms: T,
+        now: T,
+    ) -> WatchdogGuard<'r, T> {
+        self.add(node.as_mut(), timeout_ms, now);
+
+        WatchdogGuard {
+            registry: self,
+            node,
+        }
+    }
+
+    /// Remove a previously registered watchdog from the registry.
+    ///
+    /// Walks the linked list to confirm the node is registered here, unlinks
+    /// it, and clears its `next` and `prev` pointers. If the node is not
+    /// found the call is a no-op. Use
+    /// [`remove_unchecked`](Self::remove_unchecked) to skip the walk when
+    /// membership is already known.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node to remove.
+    pub fn remove(&mut self, node: Pin<&mut WatchdogNodeT<T>>) {
+        if self.contains_ptr(&raw const *node) {
+            // SAFETY: the node was just found in this registry's list.
+            unsafe {
+                self.remove_unchecked(node);
+            }
+        }
+    }
+
+    /// Remove a registered watchdog in constant time.
+    ///
+    /// Unlike [`remove`](Self::remove), the list is not walked to confirm
+    /// membership: the node is unlinked through its own `prev` and `next`
+    /// pointers. This keeps removal O(1) for systems that frequently add
+    /// and remove short-lived watchdogs.
+    ///
+    /// # Safety
+    /// `node` must be currently registered in **this** registry. Passing a
+    /// node that was never added, has already been removed, is linked into
+    /// another registry, or was left dangling by [`init`](Self::init)
+    /// corrupts the list.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node to remove.
+    pub unsafe fn remove_unchecked(&mut self, node: Pin<&mut WatchdogNodeT<T>>) {
+        // SAFETY: We only read the address; we do not move the node.
+        let node_ptr: *mut WatchdogNodeT<T> = unsafe { &raw mut *node.get_unchecked_mut() };
+
+        // SAFETY: the caller guarantees `node_ptr` is in this list, so its
+        // `prev` is its predecessor (or null for the head).
+        unsafe {
+            self.unlink((*node_ptr).prev, node_ptr);
+        }
+    }
+
+    /// Remove a watchdog and clear the latch if that node tripped it.
+    ///
+    /// For systems where dropping the faulting task *is* the recovery. The
+    /// node is removed like with [`remove`](Self::remove); if it was
+    /// registered and its id equals [`first_expired_id`](Self::first_expired_id),
+    /// the latch is cleared as with [`clear_expired`](Self::clear_expired).
+    /// Removing any other node leaves the latch untouched.
+    ///
+    /// # Warning
+    ///
+    /// Only the node that tripped the latch is known; other nodes may have
+    /// expired since and are reported again only by the next scan. Ids are
+    /// compared, so nodes sharing the cause's id are indistinguishable from
+    /// it. The caveats of `clear_expired` about gating a hardware watchdog
+    /// apply.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node to remove.
+    ///
+    /// # Returns
+    /// `true` if the latch was cleared.
+    pub fn remove_and_clear_if_cause(&mut self, node: Pin<&mut WatchdogNodeT<T>>) -> bool {
+        let registered = self.contains_ptr(&raw const *node);
+        let is_cause = registered && self.first_expired_id() == Some(node.id);
+
+        self.remove(node);
+        if is_cause {
+            self.clear_expired();
+        }
+
+        is_cause
+    }
+
+    /// Unlink `current` from the list, clear its `next` and `prev` pointers
+    /// and linked flag, and return its former successor.
+    ///
+    /// # Safety
+    /// `current` must be a node in this registry's list and `prev` its
+    /// predecessor, or null if `current` is the head.
+    unsafe fn unlink(
+        &mut self,
+        prev: *mut WatchdogNodeT<T>,
+        current: *mut WatchdogNodeT<T>,
+    ) -> *mut WatchdogNodeT<T> {
+        // SAFETY: guaranteed by the caller — both pointers are valid nodes
+        // of this list (or `prev` is null).
+        unsafe {
+            debug_assert_eq!((*current).prev, prev, "stale back link");
+            let next = (*current).next;
+            if prev.is_null() {
+                // Removing the head of the list.
+                self.head = next;
+            } else {
+                // Removing from the middle or tail.
+                (*prev).next = next;
+            }
+            if next.is_null() {
+                // The last node was removed; its predecessor (or null for a
+                // now-empty list) becomes the new tail.
+                self.tail = prev;
+            } else {
+                (*next).prev = prev;
+            }
+            #[cfg(feature = "log")]
+            log::debug!("watchdog {} unregistered", (*current).id);
+            (*current).next = ptr::null_mut();
+            (*current).prev = ptr::null_mut();
+            (*current).linked = false;
+            self.node_count -= 1;
+            self.generation = self.generation.wrapping_add(1);
+            next
+        }
+    }
+
+    /// Remove every registered watchdog from the registry.
+    ///
+    /// Walks the list, clears each node's links and empties the
+    /// list, so all nodes can safely be re-added later. Unlike
+    /// [`init`](Self::init), the expiration latch and the registry
+    /// configuration are left untouched.
+    pub fn remove_all(&mut self) {
+        let mut current = self.head;
+        while !current.is_null() {
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. We read its successor before clearing the
+            // link; the node itself is not moved.
+            unsafe {
+                let next = (*current).next;
+                (*current).next = ptr::null_mut();
+                (*current).prev = ptr::null_mut();
+                (*current).linked = false;
+                current = next;
+            }
+        }
+        self.head = ptr::null_mut();
+        self.tail = ptr::null_mut();
+        self.node_count = 0;
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Move every node registered in `other` to the end of this registry.
+    ///
+    /// `other`'s list is spliced onto this one in constant time, preserving
+    /// its order, and `other` is left empty. The expiration latch is OR-ed:
+    /// if only `other` had latched, this registry adopts its detection
+    /// timestamp. `other`'s own latch and configuration are left untouched.
+    ///
+    /// The moved nodes keep the pinning contract they were registered
+    /// under: they must stay pinned and alive for as long as they are
+    /// linked into this registry.
+    ///
+    /// # Parameters
+    /// - `other`: the registry whose nodes are moved.
+    ///
+    /// # Errors
+    /// - [`WatchdogError::Full`] if the combined list would exceed this
+    ///   registry's [capacity](Self::with_capacity). Neither registry is
+    ///   modified.
+    pub fn merge(&mut self, other: &mut WatchdogRegistryT<T>) -> Result<(), WatchdogError> {
+        if other.head.is_null() {
+            return Ok(());
+        }
+        if other.node_count > self.max_nodes - self.node_count {
+            return Err(WatchdogError::Full);
+        }
+
+        if self.tail.is_null() {
+            self.head = other.head;
+        } else {
+            // SAFETY: a non-null `tail` is the last valid node in the list
+            // and `other.head` the first valid node of `other`'s list.
+            unsafe {
+                (*self.tail).next = other.head;
+                (*other.head).prev = self.tail;
+            }
+        }
+        self.tail = other.tail;
+        self.node_count += other.node_count;
+        other.head = ptr::null_mut();
+        other.tail = ptr::null_mut();
+        other.node_count = 0;
+        other.generation = other.generation.wrapping_add(1);
+
+        if other.expired && !self.expired {
+            self.expired = true;
+            self.expired_at_ms = other.expired_at_ms;
+            self.expired_id = other.expired_id;
+            self.scanned_at_ms = other.scanned_at_ms;
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the node is currently registered in this registry.
+    ///
+    /// Walks the linked list and compares each entry against the node's
+    /// address by raw pointer, exactly like the duplicate detection in
+    /// [`add`](Self::add).
+    ///
+    /// # Parameters
+    /// - `node`: a pinned shared reference to the watchdog node.
+    #[must_use]
+    pub fn contains(&self, node: Pin<&WatchdogNodeT<T>>) -> bool {
+        self.contains_ptr(&raw const *node)
+    }
+
+    /// Returns `true` if any registered node has the given id.
+    ///
+    /// Unlike [`contains`](Self::contains), nodes are matched by their
+    /// logical id rather than by address, mirroring
+    /// [`feed_by_id`](Self::feed_by_id). Ids are not required to be unique,
+    /// so several nodes may match.
+    ///
+    /// # Parameters
+    /// - `id`: the identifier to look for.
+    #[must_use]
+    pub fn contains_id(&self, id: u32) -> bool {
+        self.iter_ids().any(|node_id| node_id == id)
+    }
+
+    /// Iterate over the ids of all registered watchdog nodes.
+    ///
+    /// Yields each node's [`id`](WatchdogNodeT::id) from the head of the list
+    /// to the tail, regardless of its health and without requiring a prior
+    /// [`check`](Self::check). The iterator borrows the registry, so nodes
+    /// cannot be added or removed while it is alive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use mwdg::WatchdogRegistry;
+    /// # let registry = WatchdogRegistry::new();
+    /// for id in registry.iter_ids() {
+    ///     // report watchdog `id`
+    /// }
+    /// ```
+    pub fn iter_ids(&self) -> impl Iterator<Item = u32> + '_ {
+        self.iter().map(|node| node.id)
+    }
+
+    /// Iterate over all registered watchdog nodes.
+    ///
+    /// Yields a pinned shared reference to each node from the head of the
+    /// list to the tail, giving read-only access to its configuration. The
+    /// iterator borrows the registry, so the list cannot be modified while
+    /// it is alive. `&registry` also implements [`IntoIterator`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use mwdg::WatchdogRegistry;
+    /// # let registry = WatchdogRegistry::new();
+    /// for node in &registry {
+    ///     let (id, timeout) = (node.id(), node.timeout_interval_ms());
+    ///     // report watchdog `id` with its configured `timeout`
+    /// }
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            current: self.head.cast_const(),
+            _registry: PhantomData,
+        }
+    }
+
+    /// Returns the first registered node, or null for an empty registry.
+    ///
+    /// Meant for FFI adapters that expose the list to C. The pointer is only
+    /// valid until the registry is next mutated, and the nodes reachable
+    /// from it belong to the registry: they must not be written through it.
+    /// Prefer [`iter`](Self::iter) in Rust code.
+    #[must_use]
+    pub fn head_ptr(&self) -> *const WatchdogNodeT<T> {
+        self.head.cast_const()
+    }
+
+    /// Apply `f` to every registered node in list order.
+    ///
+    /// Each node is handed out as a pinned mutable reference, so the
+    /// closure can use the static per-node operations such as
+    /// [`set_timeout`](Self::set_timeout) or [`feed`](Self::feed) without
+    /// the caller walking the list. The registry itself stays borrowed, so
+    /// the list cannot be modified from inside `f`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use mwdg::WatchdogRegistry;
+    /// # let mut registry = WatchdogRegistry::new();
+    /// // Low-power mode: give every task twice as long.
+    /// registry.each_mut(|node| {
+    ///     let timeout = node.timeout_interval_ms();
+    ///     WatchdogRegistry::set_timeout(node, timeout.saturating_mul(2));
+    /// });
+    /// ```
+    pub fn each_mut(&mut self, mut f: impl FnMut(Pin<&mut WatchdogNodeT<T>>)) {
+        let mut current = self.head;
+        while !current.is_null() {
+            let node_ptr = current;
+            // SAFETY: `current` is non-null and points to a valid node in
+            // the list. The link is read before the node is handed out.
+            current = unsafe { (*node_ptr).next };
+            // SAFETY: the node is pinned by API contract and `&mut self`
+            // guarantees exclusive access; the closure can neither move the
+            // node nor reach its private `next` link.
+            f(unsafe { Pin::new_unchecked(&mut *node_ptr) });
+        }
+    }
+
+    /// Unregister every node, yielding each one as it is unlinked.
+    ///
+    /// Nodes are removed from the head in registration order, with their
+    /// `next` pointers cleared exactly like [`remove`](Self::remove), so the
+    /// caller can run per-node cleanup. Each node is detached before it is
+    /// yielded, so no two yielded references alias. If the iterator is
+    /// dropped early, the remaining nodes are still unregistered; the
+    /// registry is always empty afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use mwdg::{WatchdogNode, WatchdogRegistry};
+    /// # use core::pin::pin;
+    /// # let mut registry = WatchdogRegistry::new();
+    /// # let mut node = pin!(WatchdogNode::default());
+    /// # registry.add(node.as_mut(), 100, 0);
+    /// for node in registry.drain() {
+    ///     WatchdogRegistry::pause(node);
+    /// }
+    /// assert_eq!(registry.iter().count(), 0);
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain { registry: self }
+    }
+
+    /// Check that the intrusive list is well-formed.
+    ///
+    /// A misused node (e.g. linked into two registries) can corrupt the list
+    /// into a cycle, which would make [`check`](Self::check) loop forever.
+    /// This walks the list with Floyd's tortoise-and-hare, so it always
+    /// terminates, and additionally verifies that every back link points
+    /// at the preceding node and that the list ends at the recorded tail
+    /// and holds the recorded number of nodes.
+    /// Intended for debug builds and assertions.
+    ///
+    /// # Parameters
+    /// - `max_nodes`: upper bound on the expected number of nodes.
+    ///
+    /// # Returns
+    /// `true` if the list is acyclic, holds at most `max_nodes` nodes, has
+    /// consistent back links and a consistent length, and ends at the tail;
+    /// `false` otherwise.
+    #[must_use]
+    pub fn verify_integrity(&self, max_nodes: usize) -> bool {
+        let mut count = 0;
+        let mut last: *const WatchdogNodeT<T> = ptr::null();
+        let mut slow = self.head.cast_const();
+        let mut fast = self.head.cast_const();
+        while !fast.is_null() {
+            count += 1;
+            if count > max_nodes {
+                return false;
+            }
+            // SAFETY: `fast` is non-null and points to a valid node in the
+            // list. We only read the back link.
+            if unsafe { (*fast).prev.cast_const() } != last {
+                return false;
+            }
+            last = fast;
+            // SAFETY: `fast` is non-null and points to a valid node in the
+            // list. We only read the link.
+            fast = unsafe { (*fast).next.cast_const() };
+
+            // The hare advances two steps for every step of the tortoise.
+            if count % 2 == 0 {
+                // SAFETY: `slow` trails `fast` on the same path, so it is a
+                // non-null node that was already visited.
+                slow = unsafe { (*slow).next.cast_const() };
+                if slow == fast {
+                    return false;
+                }
+            }
+        }
+
+        last == self.tail.cast_const() && count == self.node_count
+    }
+
+    /// Ticks elapsed from `then` to `now` according to the registry's mode:
+    /// wrapping by default, saturating for
+    /// [`new_saturating`](Self::new_saturating) registries.
+    fn elapsed_ticks(&self, now: T, then: T) -> T {
+        if self.saturating {
+            now.saturating_sub(then)
+        } else {
+            now.wrapping_sub(then)
+        }
+    }
+
+    /// Like [`elapsed_ticks`](Self::elapsed_ticks), but `None` if `then` is
+    /// in the future relative to `now`, see [`elapsed_since`]. Saturating
+    /// registries clamp such differences to zero instead.
+    fn elapsed_ticks_since(&self, now: T, then: T) -> Option<T> {
+        if self.saturating {
+            Some(now.saturating_sub(then))
+        } else {
+            elapsed_since(now, then)
+        }
+    }
+
+    /// Convert a tick count to milliseconds, saturating at `T::MAX`.
+    fn ticks_to_ms(&self, ticks: T) -> T {
+        if self.tick_scale_ms == 1 {
+            ticks
+        } else {
+            ticks.saturating_scale(self.tick_scale_ms)
+        }
+    }
+
+    /// Milliseconds elapsed from `then` to `now`, see
+    /// [`elapsed_ticks`](Self::elapsed_ticks) and
+    /// [`new_with_tick`](Self::new_with_tick).
+    fn elapsed(&self, now: T, then: T) -> T {
+        self.ticks_to_ms(self.elapsed_ticks(now, then))
+    }
+
+    /// Milliseconds since `node` was last fed, or `None` if that feed is
+    /// "in the future" relative to `now`, see
+    /// [`elapsed_ticks_since`](Self::elapsed_ticks_since). The guard applies
+    /// to the raw tick count, before scaling to milliseconds.
+    fn since_feed(&self, node: &WatchdogNodeT<T>, now: T) -> Option<T> {
+        self.elapsed_ticks_since(now, node.last_touched_timestamp_ms)
+            .map(|ticks| self.ticks_to_ms(ticks))
+    }
+
+    /// Returns `true` if `node` counts as expired `elapsed` ms after its last
+    /// feed, honouring the [grace period](Self::set_grace_ms) and the
+    /// [inclusive](Self::new_inclusive) boundary, or if it was fed earlier
+    /// than its [window](Self::set_window) allows. Paused nodes never expire.
+    ///
+    /// This is the single place where an offset is applied to a timeout;
+    /// it must stay saturating so that huge timeouts cannot wrap.
+    fn is_past_timeout(&self, node: &WatchdogNodeT<T>, elapsed: T) -> bool {
+        if node.paused {
+            return false;
+        }
+
+        if node.min_interval_ms != T::ZERO && node.feed_gap_ms < node.min_interval_ms {
+            return true;
+        }
+
+        // The first check after a feed is not a miss.
+        if node.max_missed != 0 {
+            return node.missed_checks > node.max_missed;
+        }
+
+        let timeout = node.timeout_interval_ms.saturating_add(self.grace_ms);
+        if self.inclusive {
+            elapsed >= timeout
+        } else {
+            elapsed > timeout
+        }
+    }
+
+    /// [`is_past_timeout`](Self::is_past_timeout) for an elapsed time that
+    /// is `None` when the node was fed "in the future", see
+    /// [`elapsed_since`]. Such a node has not expired unless it counts
+    /// [missed checks](Self::add_by_misses), which do not depend on time.
+    fn is_past_timeout_since(&self, node: &WatchdogNodeT<T>, elapsed: Option<T>) -> bool {
+        if node.max_missed != 0 {
+            return self.is_past_timeout(node, T::ZERO);
+        }
+
+        elapsed.is_some_and(|elapsed| self.is_past_timeout(node, elapsed))
+    }
+
+    /// Whether `node` counts as expired at `now`, see
+    /// [`is_past_timeout_since`](Self::is_past_timeout_since), and the
+    /// milliseconds it has left before it does. The remaining time includes
+    /// the [grace period](Self::set_grace_ms) and is `0` for an expired
+    /// node. In the default exclusive mode a node can still have `0` left
+    /// at the very boundary without being expired yet.
+    fn expiry_at(&self, node: &WatchdogNodeT<T>, now: T) -> (bool, T) {
+        let elapsed = self.since_feed(node, now);
+        if self.is_past_timeout_since(node, elapsed) {
+            return (true, T::ZERO);
+        }
+
+        let remaining = node
+            .timeout_interval_ms
+            .saturating_add(self.grace_ms)
+            .saturating_sub(elapsed.unwrap_or(T::ZERO));
+        (false, remaining)
+    }
+
+    /// Returns `true` if `node_ptr` is linked into this registry's list.
+    fn contains_ptr(&self, node_ptr: *const WatchdogNodeT<T>) -> bool {
+        let mut current = self.head.cast_const();
+        while !current.is_null() {
+            if current == node_ptr {
+                return true;
+            }
+            // SAFETY: `current` is non-null and points to a valid node in
+            // the list (all nodes are pinned and alive by API contract).
+            current = unsafe { (*current).next.cast_const() };
+        }
+
+        false
+    }
+
+    /// Feed (touch) a watchdog, resetting its timestamp to `now`.
+    ///
+    /// Must be called periodically by the owning task to signal liveness.
+    /// This is a static method — it does not require `&mut self` because it
+    /// only writes to the node itself, not to the registry.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node to feed.
+    /// - `now`: the current timestamp in milliseconds.
+    pub fn feed(node: Pin<&mut WatchdogNodeT<T>>, now: T) {
+        // SAFETY: We are writing to a field of the pinned node. We do not
+        // move the node. The caller guarantees the node is alive.
+        unsafe {
+            node.get_unchecked_mut().touch(now);
+        }
+    }
+
+    /// Configure a feed window: the node must be fed no sooner than
+    /// `min_ms` and no later than `max_ms` after its previous feed.
+    ///
+    /// `max_ms` replaces the timeout, exactly like
+    /// [`set_timeout`](Self::set_timeout). With a non-zero `min_ms` the
+    /// checks also flag the node as expired while the last two feeds were
+    /// closer together than `min_ms`, which catches a runaway loop feeding
+    /// far more often than its fixed cadence. The first feed after
+    /// [`add`](Self::add) or [`resume`](Self::resume) is never too early.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `min_ms`: minimum interval between feeds; `0` disables the check.
+    /// - `max_ms`: timeout interval in milliseconds.
+    pub fn set_window(node: Pin<&mut WatchdogNodeT<T>>, min_ms: T, max_ms: T) {
+        // SAFETY: Writing to fields; not moving the node.
+        unsafe {
+            let node = node.get_unchecked_mut();
+            node.min_interval_ms = min_ms;
+            node.timeout_interval_ms = max_ms;
+        }
+    }
+
+    /// Change a watchdog's timeout interval without feeding it.
+    ///
+    /// Only the timeout is written; the last-feed timestamp is preserved, so
+    /// tightening a timeout mid-run does not grant the node a fresh full
+    /// interval (unlike re-[`add`](Self::add)ing it). Like
+    /// [`feed`](Self::feed), this is a static method.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `timeout_ms`: new timeout interval in milliseconds.
+    pub fn set_timeout(node: Pin<&mut WatchdogNodeT<T>>, timeout_ms: T) {
+        // SAFETY: Writing to a field; not moving the node.
+        unsafe {
+            node.get_unchecked_mut().timeout_interval_ms = timeout_ms;
+        }
+    }
+
+    /// Feed a watchdog only if it is registered in this registry.
+    ///
+    /// Unlike the static [`feed`](Self::feed), which writes the timestamp
+    /// unconditionally, this walks the list first, so a task that keeps
+    /// feeding after its node was removed is detected instead of "feeding
+    /// into the void".
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// `true` if the node was found and fed, `false` if it is not
+    /// registered (its timestamp is left untouched).
+    pub fn feed_checked(&mut self, node: Pin<&mut WatchdogNodeT<T>>, now: T) -> bool {
+        // SAFETY: We only read the address; we do not move the node.
+        let node_ptr: *mut WatchdogNodeT<T> = unsafe { &raw mut *node.get_unchecked_mut() };
+
+        let mut prev: *mut WatchdogNodeT<T> = ptr::null_mut();
+        let mut current = self.head;
+        while !current.is_null() {
+            if current == node_ptr {
+                // SAFETY: `current` is a valid, pinned node of this list and
+                // `prev` its predecessor (or null for the head).
+                unsafe {
+                    (*current).touch(now);
+                    self.fed_relink(prev, current);
+                }
+                return true;
+            }
+            prev = current;
+            // SAFETY: `current` is non-null, valid, and in the list.
+            current = unsafe { (*current).next };
+        }
+
+        false
+    }
+
+    /// Feed a registered watchdog and return the time since its previous
+    /// feed.
+    ///
+    /// Combines [`feed_checked`](Self::feed_checked) with a read of the
+    /// node's elapsed time, so feed latency can be instrumented in one
+    /// locked call. The gap is measured with the registry's elapsed-time
+    /// arithmetic (wrapping by default), like [`check`](Self::check).
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// The milliseconds elapsed between the previous feed (or registration)
+    /// and `now`, or `None` if the node is not registered (its timestamp is
+    /// left untouched).
+    pub fn feed_timed(&mut self, node: Pin<&mut WatchdogNodeT<T>>, now: T) -> Option<T> {
+        let last_touched = node.last_touched_timestamp_ms;
+        self.feed_checked(node, now)
+            .then(|| self.elapsed(now, last_touched))
+    }
+
+    /// Move the node just fed to the tail of the list if
+    /// [`set_feed_to_tail`](Self::set_feed_to_tail) is enabled.
+    ///
+    /// # Safety
+    /// `current` must be a valid node of this list and `prev` its
+    /// predecessor, or null if `current` is the head.
+    unsafe fn fed_relink(&mut self, prev: *mut WatchdogNodeT<T>, current: *mut WatchdogNodeT<T>) {
+        if !self.feed_to_tail || current == self.tail {
+            return;
+        }
+
+        // SAFETY: guaranteed by the caller; `current` is not the tail, so
+        // `tail` is a different valid node of this list.
+        unsafe {
+            let next = (*current).next;
+            if prev.is_null() {
+                self.head = next;
+            } else {
+                (*prev).next = next;
+            }
+            (*next).prev = prev;
+            (*current).next = ptr::null_mut();
+            (*current).prev = self.tail;
+            (*self.tail).next = current;
+        }
+        self.tail = current;
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Suspend monitoring of a watchdog node.
+    ///
+    /// The node keeps its position in the list, but [`check`](Self::check),
+    /// [`check_all`](Self::check_all), [`next_expired`](Self::next_expired)
+    /// and the other scans skip it entirely until it is
+    /// [resumed](Self::resume). Useful when a task is legitimately suspended,
+    /// e.g. during a firmware update.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    pub fn pause(node: Pin<&mut WatchdogNodeT<T>>) {
+        // SAFETY: Writing to a field; not moving the node.
+        unsafe {
+            node.get_unchecked_mut().paused = true;
+        }
+    }
+
+    /// Resume monitoring of a [paused](Self::pause) watchdog node.
+    ///
+    /// The node's timestamp is reset to `now`, so the time spent paused does
+    /// not cause an instant expiry. A [deadline](Self::add_deadline) node
+    /// keeps its absolute deadline.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `now`: the current timestamp in milliseconds.
+    pub fn resume(node: Pin<&mut WatchdogNodeT<T>>, now: T) {
+        // SAFETY: Writing to fields; not moving the node.
+        unsafe {
+            let node = node.get_unchecked_mut();
+            node.paused = false;
+            if !node.deadline {
+                node.last_touched_timestamp_ms = now;
+            }
+            node.feed_gap_ms = T::MAX;
+            node.fed = false;
+            node.missed_checks = 0;
+        }
+    }
+
+    /// Feed a registered watchdog identified by its user-assigned id.
+    ///
+    /// Walks the list and resets the timestamp of the first node whose
+    /// [`id`](WatchdogNodeT::id) equals `id`. This allows a task that does
+    /// not own the node (e.g. a message dispatcher relaying heartbeats) to
+    /// feed it on the owner's behalf.
+    ///
+    /// If several nodes share the same id, only the first one in list order
+    /// is fed.
+    ///
+    /// # Parameters
+    /// - `id`: the identifier of the node to feed.
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// `true` if a node with the given id was found and fed, `false`
+    /// otherwise.
+    pub fn feed_by_id(&mut self, id: u32, now: T) -> bool {
+        let mut prev: *mut WatchdogNodeT<T> = ptr::null_mut();
+        let mut current = self.head;
+        while !current.is_null() {
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. `&mut self` guarantees exclusive access to
+            // the list, and we only write a field — the node is not moved.
+            let node = unsafe { &mut *current };
+            if node.id == id {
+                node.touch(now);
+                // SAFETY: `current` is in the list and `prev` is its
+                // predecessor (or null for the head).
+                unsafe {
+                    self.fed_relink(prev, current);
+                }
+                return true;
+            }
+
+            prev = current;
+            current = node.next;
+        }
+
+        false
+    }
+
+    /// Feed a batch of registered watchdogs identified by their ids.
+    ///
+    /// Applies [`feed_by_id`](Self::feed_by_id) to every id in `ids`, so a
+    /// dispatcher collecting heartbeats per tick can feed them all within a
+    /// single critical section. Each lookup walks the list, making this
+    /// `O(ids × nodes)`.
+    ///
+    /// # Parameters
+    /// - `ids`: the identifiers of the nodes to feed.
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// The number of ids that matched a registered node.
+    pub fn feed_all_with_ids(&mut self, ids: &[u32], now: T) -> usize {
+        ids.iter().filter(|&&id| self.feed_by_id(id, now)).count()
+    }
+
+    /// Feed every registered watchdog at once.
+    ///
+    /// Sets each node's `last_touched_timestamp_ms` to `now`. Intended for a
+    /// supervisor that knows all tasks were legitimately stalled, e.g. by a
+    /// long flash erase. The expiration latch is **not** cleared — use
+    /// [`clear_expired`](Self::clear_expired) for that.
+    /// [Deadline](Self::add_deadline) nodes keep their absolute deadline,
+    /// just as feeding them does not move it.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    pub fn reset_all(&mut self, now: T) {
+        let mut current = self.head;
+        while !current.is_null() {
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. `&mut self` guarantees exclusive access to
+            // the list, and we only write a field — the node is not moved.
+            let node = unsafe { &mut *current };
+            if !node.deadline {
+                node.last_touched_timestamp_ms = now;
+            }
+            node.feed_gap_ms = T::MAX;
+            node.fed = false;
+            node.missed_checks = 0;
+            current = node.next;
+        }
+    }
+
+    /// Assign a user-defined identifier to a watchdog node.
+    ///
+    /// The identifier can be set at any time — before or after adding the
+    /// node to a registry. It is never modified by the library; it is purely
+    /// for the caller to identify expired nodes via
+    /// [`next_expired`](Self::next_expired).
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `id`: the identifier to assign.
+    pub fn assign_id(node: Pin<&mut WatchdogNodeT<T>>, id: u32) {
+        // SAFETY: Writing to a field; not moving the node.
+        unsafe {
+            node.get_unchecked_mut().id = id;
+        }
+    }
+
+    /// Attach a human-readable name to a watchdog node for diagnostics.
+    ///
+    /// Like [`assign_id`](Self::assign_id), the name can be set at any time
+    /// and is never interpreted by the library. It is a borrowed
+    /// `&'static str`, so no allocation is involved; it is reported by
+    /// [`WatchdogNodeT::name`] and [`next_expiry_event`](Self::next_expiry_event).
+    /// Only available with the `names` feature.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `name`: the name to attach.
+    #[cfg(feature = "names")]
+    pub fn set_name(node: Pin<&mut WatchdogNodeT<T>>, name: &'static str) {
+        // SAFETY: Writing to a field; not moving the node.
+        unsafe {
+            node.get_unchecked_mut().name = Some(name);
+        }
+    }
+
+    /// Set the early-warning interval of a watchdog node.
+    ///
+    /// Once the time since the last feed exceeds `warn_ms` (but not yet the
+    /// timeout) the node is reported by
+    /// [`check_warnings`](Self::check_warnings). A value of `0` disables the
+    /// warning. Like [`assign_id`](Self::assign_id), this can be called
+    /// before or after the node is added.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `warn_ms`: warning interval in milliseconds.
+    pub fn set_warn_interval(node: Pin<&mut WatchdogNodeT<T>>, warn_ms: T) {
+        // SAFETY: Writing to a field; not moving the node.
+        unsafe {
+            node.get_unchecked_mut().warn_interval_ms = warn_ms;
+        }
+    }
+
+    /// Mark a watchdog node as one-shot.
+    ///
+    /// A one-shot node guards a one-time activity such as a boot sequence.
+    /// When [`check`](Self::check) or [`check_all`](Self::check_all) finds it
+    /// expired, the node is unlinked from the registry and the registry is
+    /// **not** latched: that scan reports the expiration, but later scans no
+    /// longer see the node. Because [`next_expired`](Self::next_expired)
+    /// only walks nodes that are still registered, an expired one-shot node
+    /// is never reported there — use the
+    /// [`set_on_expire`](Self::set_on_expire) hook or
+    /// [`contains`](Self::contains) to find out which one fired.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `one_shot`: `true` to make the node one-shot, `false` to restore the
+    ///   default latching behaviour.
+    pub fn set_one_shot(node: Pin<&mut WatchdogNodeT<T>>, one_shot: bool) {
+        // SAFETY: Writing to a field; not moving the node.
+        unsafe {
+            node.get_unchecked_mut().one_shot = one_shot;
+        }
+    }
+
+    /// Assign a user-defined priority to a watchdog node.
+    ///
+    /// The priority lets an expiry handler tell watchdog classes apart, e.g.
+    /// hard-reset on a critical task but only log a best-effort one. Like
+    /// [`assign_id`](Self::assign_id), it can be set before or after the
+    /// node is added and is never modified by the library.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `priority`: the priority to assign.
+    pub fn set_priority(node: Pin<&mut WatchdogNodeT<T>>, priority: u8) {
+        // SAFETY: Writing to a field; not moving the node.
+        unsafe {
+            node.get_unchecked_mut().priority = priority;
+        }
+    }
+
+    /// Assign a watchdog node to a group for partial checks.
+    ///
+    /// Groups let independent subsystems (e.g. comms, sensors, actuators)
+    /// be checked separately with [`check_group`](Self::check_group). Like
+    /// [`assign_id`](Self::assign_id), the group can be set before or after
+    /// the node is added. Every node starts in group `0`.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `group`: the group to assign.
+    pub fn set_group(node: Pin<&mut WatchdogNodeT<T>>, group: u8) {
+        // SAFETY: Writing to a field; not moving the node.
+        unsafe {
+            node.get_unchecked_mut().group = group;
+        }
+    }
+
+    /// Set how many consecutive healthy scans a degraded node needs before
+    /// it recovers.
+    ///
+    /// See [`update_health`](Self::update_health) and
+    /// [`degraded`](Self::degraded). A value of `0` behaves like `1`: the
+    /// node is degraded only while the latest scan found it unhealthy.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned mutable reference to the watchdog node.
+    /// - `recover_after`: required number of consecutive healthy scans.
+    pub fn set_recover_after(node: Pin<&mut WatchdogNodeT<T>>, recover_after: u16) {
+        // SAFETY: Writing to a field; not moving the node.
+        unsafe {
+            node.get_unchecked_mut().recover_after = recover_after;
+        }
+    }
+
+    /// Returns `true` if the node is degraded, i.e. it has not yet been
+    /// healthy for [`set_recover_after`](Self::set_recover_after)
+    /// consecutive [`update_health`](Self::update_health) scans since it was
+    /// last seen in its warning band or expired.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned shared reference to the watchdog node.
+    #[must_use]
+    pub fn degraded(node: Pin<&WatchdogNodeT<T>>) -> bool {
+        node.healthy_streak < node.recover_after.max(1)
+    }
+
+    /// Compute how much time a watchdog has left before it expires.
+    ///
+    /// Uses the same wrapping arithmetic as [`check`](Self::check), so the
+    /// result is correct across overflow of the timestamp type. Saturates
+    /// to `0` once the node is past its timeout.
+    ///
+    /// # Parameters
+    /// - `node`: a pinned shared reference to the watchdog node.
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// The remaining time in milliseconds, or `0` if the node has expired.
+    #[must_use]
+    pub fn time_to_expiry(node: Pin<&WatchdogNodeT<T>>, now: T) -> T {
+        let elapsed = elapsed_since(now, node.last_touched_timestamp_ms).unwrap_or(T::ZERO);
+
+        node.timeout_interval_ms.saturating_sub(elapsed)
+    }
+
+    /// Capture the latch and per-node timing state, e.g. before an
+    /// uninstrumented phase such as a bootloader handoff.
+    ///
+    /// The last-feed timestamp of each node is written to `timestamps` in
+    /// list order until the buffer is full; the returned
+    /// [`RegistryCheckpoint`] holds the latch and records how many nodes
+    /// were captured. Nothing is allocated. The list must not be modified
+    /// before the matching [`restore`](Self::restore).
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    /// - `timestamps`: caller-supplied buffer receiving one timestamp per
+    ///   node.
+    #[must_use]
+    pub fn checkpoint(&self, now: T, timestamps: &mut [T]) -> RegistryCheckpoint<T> {
+        let nodes = timestamps
+            .iter_mut()
+            .zip(self.iter())
+            .map(|(slot, node)| *slot = node.last_touched_timestamp_ms)
+            .count();
+
+        RegistryCheckpoint {
+            taken_at_ms: now,
+            nodes,
+            expired: self.expired,
+            expired_at_ms: self.expired_at_ms,
+            expired_id: self.expired_id,
+            scanned_at_ms: self.scanned_at_ms,
+        }
+    }
+
+    /// Restore the state captured by [`checkpoint`](Self::checkpoint) as if
+    /// no time had passed since.
+    ///
+    /// Every captured timestamp, as well as the latch timestamps, is shifted
+    /// forward by the time between the checkpoint and `now`, so the phase in
+    /// between neither counts towards any timeout nor leaves a stale latch
+    /// snapshot. The latch itself is restored too: an expiration cleared in
+    /// between is reinstated, one latched in between is dropped. Nodes
+    /// beyond the captured count keep their current timestamps.
+    ///
+    /// # Parameters
+    /// - `checkpoint`: the state returned by `checkpoint`.
+    /// - `timestamps`: the buffer filled by `checkpoint`.
+    /// - `now`: the current timestamp in milliseconds.
+    pub fn restore(&mut self, checkpoint: &RegistryCheckpoint<T>, timestamps: &[T], now: T) {
+        let shift = now.wrapping_sub(checkpoint.taken_at_ms);
+
+        let mut current = self.head;
+        for &timestamp in timestamps.iter().take(checkpoint.nodes) {
+            if current.is_null() {
+                break;
+            }
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. `&mut self` guarantees exclusive access to
+            // the list, and we only write a field — the node is not moved.
+            let node = unsafe { &mut *current };
+            node.last_touched_timestamp_ms = timestamp.wrapping_add(shift);
+            current = node.next;
+        }
+
+        self.expired = checkpoint.expired;
+        self.expired_at_ms = checkpoint.expired_at_ms.wrapping_add(shift);
+        self.expired_id = checkpoint.expired_id;
+        self.scanned_at_ms = checkpoint.scanned_at_ms.wrapping_add(shift);
+    }
+
+    /// Export the status of every registered watchdog into `out`.
+    ///
+    /// Walks the list in order and writes one [`NodeStatus`] per node until
+    /// `out` is full; nodes beyond its capacity are skipped. Remaining time
+    /// and expiry use the same arithmetic and boundary as
+    /// [`check`](Self::check), but nothing is latched. No allocation is
+    /// performed.
+    ///
+    /// The buffer may hold any type convertible from [`NodeStatus`], so a
+    /// binding can fill its own record layout directly.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    /// - `out`: caller-supplied buffer receiving the records.
+    ///
+    /// # Returns
+    /// The number of records written, i.e. `min(out.len(), node count)`.
+    pub fn snapshot<S: From<NodeStatus<T>>>(&self, now: T, out: &mut [S]) -> usize {
+        let mut written = 0;
+
+        let mut current = self.head.cast_const();
+        for slot in out.iter_mut() {
+            if current.is_null() {
+                break;
+            }
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. We only read fields — no mutation, no move.
+            let node = unsafe { &*current };
+            let (expired, remaining_ms) = self.expiry_at(node, now);
+
+            *slot = S::from(NodeStatus {
+                id: node.id,
+                timeout_ms: node.timeout_interval_ms,
+                remaining_ms,
+                expired,
+            });
+            written += 1;
+
+            current = node.next.cast_const();
+        }
+
+        written
+    }
+
+    /// Find the registered watchdog closest to expiring.
+    ///
+    /// Computes every node's remaining time with the same expiry rule as
+    /// [`check`](Self::check), including the
+    /// [grace period](Self::set_grace_ms); a node that already counts as
+    /// expired has `0` remaining, so it always wins. [Paused](Self::pause)
+    /// nodes and [miss-budget](Self::add_by_misses) nodes, which do not
+    /// expire with time, are skipped. On a tie the node earlier in the
+    /// list is reported. Useful for right-sizing timeouts.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// `Some((id, remaining_ms))` of the tightest node, or `None` if no
+    /// unpaused, time-based watchdog is registered.
+    #[must_use]
+    pub fn worst_margin(&self, now: T) -> Option<(u32, T)> {
+        let mut worst: Option<(u32, T)> = None;
+
+        let mut current = self.head.cast_const();
+        while !current.is_null() {
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. We only read fields — no mutation, no move.
+            let node = unsafe { &*current };
+
+            if !node.paused && node.max_missed == 0 {
+                let (_, remaining) = self.expiry_at(node, now);
+                if worst.is_none_or(|(_, margin)| remaining < margin) {
+                    worst = Some((node.id, remaining));
+                }
+            }
+
+            current = node.next.cast_const();
+        }
+
+        worst
+    }
+
+    /// Returns the soonest time (ms) from `now` at which a
+    /// [`check`](Self::check) could fail.
+    ///
+    /// This is the smallest remaining time across all nodes, computed like
+    /// [`worst_margin`](Self::worst_margin): wrap-correct, grace included,
+    /// `0` for nodes that already count as expired, [paused](Self::pause)
+    /// and [miss-budget](Self::add_by_misses) nodes skipped. A main loop
+    /// can arm a timer with it instead of polling `check` at a fixed rate.
+    /// In the default exclusive mode the node only expires one tick after
+    /// the returned time, so the estimate errs on the early side.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// The minimum remaining time, or `None` if no unpaused, time-based
+    /// watchdog is registered.
+    #[must_use]
+    pub fn nearest_deadline(&self, now: T) -> Option<T> {
+        self.worst_margin(now).map(|(_, remaining)| remaining)
+    }
+
+    /// Check all registered watchdogs for expiration.
+    ///
+    /// Iterates the linked list of registered watchdogs. For each one,
+    /// computes elapsed time using wrapping arithmetic (safe across timestamp
+    /// overflow; saturating for [`new_saturating`](Self::new_saturating)
+    /// registries) and compares against the timeout interval.
+    ///
+    /// Once an expiration is detected the registry latches into the expired
+    /// state: all subsequent calls return `true` without re-scanning the
+    /// list, and `expired_at_ms` is frozen at the timestamp of first
+    /// detection. If a hook was registered via
+    /// [`set_on_expire`](Self::set_on_expire) it is invoked with the expired
+    /// node's id right before latching. A
+    /// [non-latching](Self::new_non_latching) registry rescans on every call
+    /// instead.
+    ///
+    /// Expired [one-shot](Self::set_one_shot) nodes are unlinked instead of
+    /// latching the registry (the hook is still invoked for them), and the
+    /// scan carries on with the remaining nodes.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// `true` if any watchdog has expired, `false` if all are healthy.
+    pub fn check(&mut self, now: T) -> bool {
+        if let Some(prev) = self.prev_check_ms.replace(now)
+            && now.wrapping_sub(prev) > T::HALF_RANGE
+            && let Some(on_time_anomaly) = self.on_time_anomaly
+        {
+            on_time_anomaly(prev, now);
+        }
+
+        self.release_non_latching();
+        if self.expired {
+            return true;
+        }
+
+        let mut one_shot_expired = false;
+        let mut prev: *mut WatchdogNodeT<T> = ptr::null_mut();
+        let mut current = self.head;
+        while !current.is_null() {
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. `&mut self` guarantees exclusive access; only
+            // the miss counter is written, before any reference is taken.
+            unsafe {
+                if (*current).max_missed != 0 && !(*current).paused {
+                    (*current).missed_checks = (*current).missed_checks.saturating_add(1);
+                }
+            }
+            // SAFETY: as above. We only read fields — no mutation, no move.
+            let node = unsafe { &*current };
+            let elapsed = self.since_feed(node, now);
+
+            if self.is_past_timeout_since(node, elapsed) {
+                if node.one_shot {
+                    one_shot_expired = true;
+                    // SAFETY: `current` is in the list and `prev` is its
+                    // predecessor (or null for the head).
+                    current = unsafe { self.expire_one_shot(prev, current) };
+                    continue;
+                }
+                // Every check is a tick for the miss-budget nodes that were
+                // not reached, or a non-latching registry could return here
+                // forever without them ever running out.
+                // SAFETY: `node.next` is null or a node of this list.
+                unsafe { Self::count_missed_checks(node.next) };
+                self.latch(node.id, now);
+                return true;
+            }
+
+            prev = current;
+            current = node.next;
+        }
+
+        one_shot_expired
+    }
+
+    /// Check only the watchdogs of one [group](Self::set_group).
+    ///
+    /// Nodes of other groups are skipped entirely, so a subsystem can be
+    /// checked without paying for a scan of the whole list. An expired node
+    /// of the group trips the registry's single, global latch exactly like
+    /// [`check`](Self::check) (expired [one-shot](Self::set_one_shot) nodes
+    /// are unlinked instead).
+    ///
+    /// Because the latch is shared, `check` reports `true` once any group
+    /// has latched. `check_group` itself does not short-circuit on the
+    /// latch: it always reflects the current state of its own group, so a
+    /// healthy group still reads `false` while another one is faulted. If
+    /// the registry is already latched, the detection time and id stay
+    /// frozen.
+    ///
+    /// # Parameters
+    /// - `group`: the group to check.
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// `true` if a watchdog of `group` has expired, `false` if all of them
+    /// are healthy.
+    pub fn check_group(&mut self, group: u8, now: T) -> bool {
+        self.release_non_latching();
+        let mut expired = false;
+        let mut prev: *mut WatchdogNodeT<T> = ptr::null_mut();
+        let mut current = self.head;
+        while !current.is_null() {
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. We only read fields — no mutation, no move.
+            let node = unsafe { &*current };
+
+            if node.group == group && self.is_past_timeout_since(node, self.since_feed(node, now)) {
+                expired = true;
+                if node.one_shot {
+                    // SAFETY: `current` is in the list and `prev` is its
+                    // predecessor (or null for the head).
+                    current = unsafe { self.expire_one_shot(prev, current) };
+                    continue;
+                }
+                if !self.expired {
+                    self.latch(node.id, now);
+                }
+                return true;
+            }
+
+            prev = current;
+            current = node.next;
+        }
+
+        expired
+    }
+
+    /// Check all registered watchdogs and return the first expired one.
+    ///
+    /// Shorthand for [`check`](Self::check) followed by a single
+    /// [`next_expired`](Self::next_expired) call with a fresh cursor: the
+    /// registry latches exactly as with `check`, and the id is looked up
+    /// against the latched snapshot. Expired [one-shot](Self::set_one_shot)
+    /// nodes are unlinked by the scan and therefore never returned.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// `Some(id)` of the first expired node in list order, or `None` if all
+    /// watchdogs are healthy.
+    pub fn check_first_expired(&mut self, now: T) -> Option<u32> {
+        if !self.check(now) {
+            return None;
+        }
+
+        self.next_expired(&mut CursorT::start())
+    }
+
+    /// Check all registered watchdogs and count how many have expired.
+    ///
+    /// Unlike [`check`](Self::check), which stops at the first expired node,
+    /// this method always scans the entire list. If at least one node is
+    /// expired the registry latches exactly like `check` does (invoking the
+    /// [`set_on_expire`](Self::set_on_expire) hook with the first expired
+    /// node's id); if it was already latched, `expired_at_ms` stays frozen
+    /// at the original detection time. Expired
+    /// [one-shot](Self::set_one_shot) nodes are counted and unlinked but do
+    /// not latch the registry.
+    ///
+    /// Being a full scan, this is also where each node's
+    /// [`min_margin_ms`](WatchdogNodeT::min_margin_ms) high-water mark is
+    /// updated, keeping `check` itself on the fast path.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// The number of nodes expired at `now`.
+    pub fn check_all(&mut self, now: T) -> usize {
+        let mut count = 0;
+        self.scan(now, |_, expired| count += usize::from(expired));
+        count
+    }
+
+    /// Check every registered watchdog in one pass, reporting each one.
+    ///
+    /// Calls `visit(id, expired)` for every node in list order, then latches
+    /// exactly like [`check_all`](Self::check_all) if any node was expired.
+    /// This gives the overall verdict and the per-node breakdown from a
+    /// single traversal, replacing `check` followed by repeated
+    /// [`next_expired`](Self::next_expired) calls. Expired
+    /// [one-shot](Self::set_one_shot) nodes are reported and then unlinked.
+    ///
+    /// `visit` runs while the registry is mutably borrowed, typically inside
+    /// the caller's critical section, so it should be short.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    /// - `visit`: called with each node's id and whether it is expired.
+    pub fn scan(&mut self, now: T, mut visit: impl FnMut(u32, bool)) {
+        self.release_non_latching();
+        let mut first_id = None;
+
+        let mut prev: *mut WatchdogNodeT<T> = ptr::null_mut();
+        let mut current = self.head;
+        while !current.is_null() {
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. `&mut self` guarantees exclusive access to
+            // the list, and we only write a field — the node is not moved.
+            let node = unsafe { &mut *current };
+            let elapsed = self.since_feed(node, now);
+            if !node.paused {
+                let remaining = node
+                    .timeout_interval_ms
+                    .saturating_sub(elapsed.unwrap_or(T::ZERO));
+                node.min_margin_ms = node.min_margin_ms.min(remaining);
+            }
+
+            let expired = self.is_past_timeout_since(node, elapsed);
+            visit(node.id, expired);
+            if expired {
+                node.expiry_count = node.expiry_count.saturating_add(1);
+                if node.one_shot {
+                    // SAFETY: `current` is in the list and `prev` is its
+                    // predecessor (or null for the head).
+                    current = unsafe { self.expire_one_shot(prev, current) };
+                    continue;
+                }
+                first_id.get_or_insert(node.id);
+            }
+
+            prev = current;
+            current = node.next;
+        }
+
+        if let Some(id) = first_id
+            && !self.expired
+        {
+            self.latch(id, now);
+        }
+    }
+
+    /// Check every registered watchdog and summarize the outcome in one
+    /// struct.
+    ///
+    /// Performs the same full scan as [`check_all`](Self::check_all),
+    /// latching if needed, and then gathers the latch state that would
+    /// otherwise take several accessor calls. Because the whole list is
+    /// always walked, [`CheckReport::scanned`] is the number of registered
+    /// nodes at the time of the scan (including expired one-shot nodes that
+    /// it unlinked).
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    pub fn check_report(&mut self, now: T) -> CheckReport<T> {
+        let mut scanned = 0;
+        self.scan(now, |_, _| scanned += 1);
+
+        CheckReport {
+            expired: self.expired,
+            first_expired_id: self.first_expired_id(),
+            scanned,
+            expired_at_ms: self.expired_at(),
+        }
+    }
+
+    /// Unregister an expired one-shot node, invoking the on-expire hook with
+    /// its id. Returns the node's former successor.
+    ///
+    /// # Safety
+    /// Same contract as [`unlink`](Self::unlink).
+    unsafe fn expire_one_shot(
+        &mut self,
+        prev: *mut WatchdogNodeT<T>,
+        current: *mut WatchdogNodeT<T>,
+    ) -> *mut WatchdogNodeT<T> {
+        // SAFETY: `current` is a valid node of this list (caller contract).
+        let id = unsafe { (*current).id };
+        if let Some(on_expire) = self.on_expire {
+            on_expire(id);
+        }
+        // SAFETY: forwarded caller contract.
+        unsafe { self.unlink(prev, current) }
+    }
+
+    /// Count one missed check for every unpaused
+    /// [miss-budget](Self::add_by_misses) node from `current` to the tail.
+    ///
+    /// # Safety
+    /// `current` must be null or a valid node of a registry list the caller
+    /// has exclusive access to.
+    unsafe fn count_missed_checks(mut current: *mut WatchdogNodeT<T>) {
+        while !current.is_null() {
+            // SAFETY: `current` is a valid node of an exclusively borrowed
+            // list (caller contract, then by following `next` links); only
+            // a field is written.
+            let node = unsafe { &mut *current };
+            if node.max_missed != 0 && !node.paused {
+                node.missed_checks = node.missed_checks.saturating_add(1);
+            }
+            current = node.next;
+        }
+    }
+
+    /// Check all registered watchdogs, ignoring the latch.
+    ///
+    /// [`check`](Self::check) returns early once the registry has latched,
+    /// so it cannot tell whether further tasks stopped afterwards. This
+    /// method always scans the full list like [`check_all`](Self::check_all)
+    /// (latching if needed) and, while latched, moves the snapshot used by
+    /// [`next_expired`](Self::next_expired) to `now`, so a post-mortem
+    /// collector can periodically re-run it and iterate fresh results.
+    /// [`expired_at`](Self::expired_at) keeps reporting the first detection.
+    ///
+    /// This is more expensive than `check`: every call walks the whole list
+    /// instead of returning immediately once latched.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// `true` if any watchdog is expired at `now`. Unlike `check`, this
+    /// reflects the scan itself rather than the latch.
+    pub fn check_force(&mut self, now: T) -> bool {
+        let expired = self.check_all(now) > 0;
+        if self.expired {
+            self.scanned_at_ms = now;
+        }
+
+        expired
+    }
+
+    /// Drop the expired state before a scan of a
+    /// [non-latching](Self::new_non_latching) registry, so the scan
+    /// recomputes it from scratch.
+    fn release_non_latching(&mut self) {
+        if self.non_latching {
+            self.expired = false;
+        }
+    }
+
+    /// Latch the registry into the expired state at `now`, invoking the
+    /// on-expire hook with the id of the node that tripped it.
+    fn latch(&mut self, id: u32, now: T) {
+        #[cfg(feature = "log")]
+        log::warn!("watchdog {id} expired at {now:?}");
+        if let Some(on_expire) = self.on_expire {
+            on_expire(id);
+        }
+        self.expired = true;
+        self.expired_at_ms = now;
+        self.expired_id = id;
+        self.scanned_at_ms = now;
+    }
+
+    /// Get the next expired watchdog in the iteration as an [`ExpiryEvent`].
+    ///
+    /// Same cursor protocol as [`next_expired`](Self::next_expired); the
+    /// returned event additionally carries the timestamp at which the
+    /// registry latched, which is convenient for logging.
+    ///
+    /// # Parameters
+    /// - `cursor`: iteration state, see [`next_expired`](Self::next_expired).
+    ///
+    /// # Returns
+    /// - `Some(event)` if an expired node was found.
+    /// - `None` when no more expired nodes remain, or if [`check`](Self::check)
+    ///   has not yet detected an expiration.
+    pub fn next_expiry_event(&self, cursor: &mut CursorT<T>) -> Option<ExpiryEvent<T>> {
+        self.next_expired_node(cursor).map(|node| ExpiryEvent {
+            id: node.id,
+            expired_at_ms: self.expired_at_ms,
+            #[cfg(feature = "names")]
+            name: node.name,
+        })
+    }
+
+    /// Raw-pointer variant of [`next_expiry_event`](Self::next_expiry_event).
+    #[deprecated(note = "use `next_expiry_event` with a `Cursor`")]
+    pub fn next_expiry_event_raw(
+        &self,
+        cursor: &mut *const WatchdogNodeT<T>,
+    ) -> Option<ExpiryEvent<T>> {
+        with_raw_cursor(cursor, |cursor| self.next_expiry_event(cursor))
+    }
+
+    /// Check whether any registered watchdog is in its warning band.
+    ///
+    /// A node is in the warning band when it has a non-zero
+    /// [`warn_interval_ms`](WatchdogNodeT::warn_interval_ms) and the time
+    /// since its last feed exceeds that interval but not yet its timeout.
+    /// Nodes that are fully expired are **not** reported — that is the job
+    /// of [`check`](Self::check).
+    ///
+    /// Unlike `check`, this method never latches and does not modify the
+    /// registry.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// `true` if at least one node is in its warning band.
+    #[must_use]
+    pub fn check_warnings(&self, now: T) -> bool {
+        let mut current = self.head;
+        while !current.is_null() {
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. We only read fields.
+            let node = unsafe { &*current };
+
+            if self.is_in_warning_band(node, now) {
+                return true;
+            }
+
+            current = node.next;
+        }
+
+        false
+    }
+
+    /// Get the next watchdog in its warning band.
+    ///
+    /// Same cursor protocol as [`next_expired`](Self::next_expired), but
+    /// nodes are evaluated against the caller's `now` with the same
+    /// criterion as [`check_warnings`](Self::check_warnings): past the
+    /// warning interval, not yet expired. No prior `check` is required and
+    /// nothing is latched.
+    ///
+    /// # Parameters
+    /// - `cursor`: iteration state, see [`next_expired`](Self::next_expired).
+    /// - `now`: the current timestamp in milliseconds.
+    ///
+    /// # Returns
+    /// - `Some(id)` if a node in its warning band was found.
+    /// - `None` when no more such nodes remain.
+    pub fn next_warning(&self, cursor: &mut CursorT<T>, now: T) -> Option<u32> {
+        let mut current = self.cursor_start(*cursor);
+        while !current.is_null() {
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. We only read fields.
+            let node = unsafe { &*current };
+
+            if self.is_in_warning_band(node, now) {
+                self.advance(cursor, current);
+                return Some(node.id);
+            }
+
+            current = node.next.cast_const();
+        }
+
+        None
+    }
+
+    /// Raw-pointer variant of [`next_warning`](Self::next_warning).
+    #[deprecated(note = "use `next_warning` with a `Cursor`")]
+    pub fn next_warning_raw(&self, cursor: &mut *const WatchdogNodeT<T>, now: T) -> Option<u32> {
+        with_raw_cursor(cursor, |cursor| self.next_warning(cursor, now))
+    }
+
+    /// Returns `true` if `node` is past its (non-zero) warning interval but
+    /// not yet expired at `now`. Paused nodes are never in the band.
+    fn is_in_warning_band(&self, node: &WatchdogNodeT<T>, now: T) -> bool {
+        let elapsed = self.since_feed(node, now);
+
+        !node.paused
+            && node.warn_interval_ms != T::ZERO
+            && elapsed.is_some_and(|elapsed| elapsed > node.warn_interval_ms)
+            && !self.is_past_timeout_since(node, elapsed)
+    }
+
+    /// Summarize the state of all registered watchdogs at `now`.
+    ///
+    /// Performs one read-only scan, combining [`check`](Self::check) and
+    /// [`check_warnings`](Self::check_warnings): any expired node yields
+    /// [`Health::Expired`], otherwise any node in its warning band yields
+    /// [`Health::Warning`]. Paused nodes are skipped.
+    ///
+    /// This never latches and does not consult the latch either; use
+    /// [`is_expired`](Self::is_expired) to also account for an expiration
+    /// detected earlier.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    #[must_use]
+    pub fn health(&self, now: T) -> Health {
+        let mut health = Health::Ok;
+        let mut current = self.head;
+        while !current.is_null() {
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. We only read fields.
+            let node = unsafe { &*current };
+            let elapsed = self.since_feed(node, now);
+
+            if self.is_past_timeout_since(node, elapsed) {
+                return Health::Expired;
+            }
+            if !node.paused
+                && node.warn_interval_ms != T::ZERO
+                && elapsed.is_some_and(|elapsed| elapsed > node.warn_interval_ms)
+            {
+                health = Health::Warning;
+            }
+
+            current = node.next;
+        }
+
+        health
+    }
+
+    /// Update every node's [degraded](Self::degraded) state.
+    ///
+    /// A node is unhealthy when it is past its
+    /// [warning interval](Self::set_warn_interval) or expired; this resets
+    /// its healthy streak. Otherwise the streak grows by one, and once it
+    /// reaches the node's [`set_recover_after`](Self::set_recover_after)
+    /// count the node is no longer degraded. The hysteresis avoids flapping
+    /// in status displays. [Paused](Self::pause) nodes are left untouched.
+    ///
+    /// This is a separate full scan so that [`check`](Self::check) stays on
+    /// its fast path; call it once per supervision cycle. It never latches.
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    pub fn update_health(&mut self, now: T) {
         let mut current = self.head;
         while !current.is_null() {
-            if current == node_ptr {
-                // Node is already registered — update timestamp and timeout.
-                // SAFETY: `node_ptr` points to a valid `WatchdogNode` that
-                // is pinned and alive (the caller holds a Pin<&mut> to it).
-                unsafe {
-                    (*node_ptr).last_touched_timestamp_ms = now;
-                    (*node_ptr).timeout_interval_ms = timeout_ms;
-                }
-                return;
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. `&mut self` guarantees exclusive access to
+            // the list, and we only write a field — the node is not moved.
+            let node = unsafe { &mut *current };
+            let elapsed = self.since_feed(node, now);
+
+            if !node.paused {
+                let warned = node.warn_interval_ms != T::ZERO
+                    && elapsed.is_some_and(|elapsed| elapsed > node.warn_interval_ms);
+                if warned || self.is_past_timeout_since(node, elapsed) {
+                    node.healthy_streak = 0;
+                } else {
+                    node.healthy_streak = node.healthy_streak.saturating_add(1);
+                }
+            }
+
+            current = node.next;
+        }
+    }
+
+    /// Reset an iteration cursor so the next call to
+    /// [`next_expired`](Self::next_expired) (or one of its variants)
+    /// restarts from the head of the list.
+    ///
+    /// Equivalent to assigning [`core::ptr::null()`], e.g. to re-check a
+    /// batch of expired nodes after handling them:
+    ///
+    /// ```rust,no_run
+    /// # #![allow(deprecated)]
+    /// # use mwdg::WatchdogRegistry;
+    /// # let registry = WatchdogRegistry::new();
+    /// let mut cursor = core::ptr::null();
+    /// while let Some(id) = registry.next_expired_raw(&mut cursor) {
+    ///     // handle expired watchdog `id`
+    /// }
+    /// WatchdogRegistry::reset_cursor(&mut cursor);
+    /// while let Some(id) = registry.next_expired_raw(&mut cursor) {
+    ///     // `id` is still expired after handling
+    /// }
+    /// ```
+    ///
+    /// [`Cursor`](CursorT) offers the same through
+    /// [`reset`](CursorT::reset).
+    pub fn reset_cursor(cursor: &mut *const WatchdogNodeT<T>) {
+        *cursor = ptr::null();
+    }
+
+    /// Returns the node an iteration resumes from: the head for a null
+    /// cursor, otherwise the cursor's successor.
+    ///
+    /// A cursor that is no longer linked into the list (its node was
+    /// removed between calls) is never dereferenced; the iteration restarts
+    /// from the head instead. Only a cursor last advanced by this registry,
+    /// with no node unlinked or relinked since, skips that O(n) membership
+    /// check.
+    fn cursor_start(&self, cursor: CursorT<T>) -> *const WatchdogNodeT<T> {
+        let node = cursor.node;
+        if node.is_null() {
+            return self.head.cast_const();
+        }
+        let trusted = ptr::eq(cursor.registry, self) && cursor.generation == self.generation;
+        if !trusted && !self.contains_ptr(node) {
+            return self.head.cast_const();
+        }
+
+        // SAFETY: `node` is linked into the list, so it points to a valid,
+        // pinned node.
+        unsafe { (*node).next.cast_const() }
+    }
+
+    /// Move `cursor` to `node`, a node of this list, remembering the
+    /// registry and its generation for [`cursor_start`](Self::cursor_start).
+    fn advance(&self, cursor: &mut CursorT<T>, node: *const WatchdogNodeT<T>) {
+        *cursor = CursorT {
+            node,
+            registry: self,
+            generation: self.generation,
+        };
+    }
+
+    /// Get the next expired watchdog node in the iteration.
+    ///
+    /// This method implements a cursor-based iterator over the linked list.
+    /// On each call it resumes from the position stored in `*cursor` and
+    /// scans forward for the next node whose elapsed time exceeds its
+    /// timeout interval. If the cursor's node has been removed from the
+    /// registry since the previous call, the iteration restarts from the
+    /// head, so nodes may be reported again. Use
+    /// [`reset_cursor`](Self::reset_cursor) to restart deliberately.
+    ///
+    /// The evaluation uses the timestamp snapshot captured by
+    /// [`check`](Self::check) (or refreshed by
+    /// [`check_force`](Self::check_force)), so nodes are compared against
+    /// the same point in time that triggered the expiration.  A half-range guard filters
+    /// out nodes whose [`feed`](Self::feed) timestamp is *ahead* of the
+    /// snapshot (i.e. they were fed between `check` and this method),
+    /// preventing `wrapping_sub` underflow from being misinterpreted as a
+    /// large elapsed time.
+    ///
+    /// # Parameters
+    /// - `cursor`: iteration state, initialized with
+    ///   [`Cursor::start`](CursorT::start) before the first call. The method
+    ///   advances the cursor to the found node on success.
+    ///
+    /// # Returns
+    /// - `Some(id)` if an expired node was found.
+    /// - `None` when no more expired nodes remain, or if [`check`](Self::check)
+    ///   has not yet detected an expiration.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use mwdg::{Cursor, WatchdogRegistry};
+    /// # let mut registry = WatchdogRegistry::new();
+    /// # let now = 0u32;
+    /// if registry.check(now) {
+    ///     let mut cursor = Cursor::start();
+    ///     while let Some(id) = registry.next_expired(&mut cursor) {
+    ///         // handle expired watchdog `id`
+    ///     }
+    /// }
+    /// ```
+    pub fn next_expired(&self, cursor: &mut CursorT<T>) -> Option<u32> {
+        self.next_expired_node(cursor).map(|node| node.id)
+    }
+
+    /// Raw-pointer variant of [`next_expired`](Self::next_expired).
+    ///
+    /// `cursor` must be initialized to [`core::ptr::null()`] before the
+    /// first call.
+    #[deprecated(note = "use `next_expired` with a `Cursor`")]
+    pub fn next_expired_raw(&self, cursor: &mut *const WatchdogNodeT<T>) -> Option<u32> {
+        with_raw_cursor(cursor, |cursor| self.next_expired(cursor))
+    }
+
+    /// Get the next expired watchdog in the iteration together with its
+    /// [priority](Self::set_priority).
+    ///
+    /// Same cursor protocol and evaluation as
+    /// [`next_expired`](Self::next_expired).
+    ///
+    /// # Parameters
+    /// - `cursor`: iteration state, see [`next_expired`](Self::next_expired).
+    ///
+    /// # Returns
+    /// - `Some((id, priority))` if an expired node was found.
+    /// - `None` when no more expired nodes remain, or if [`check`](Self::check)
+    ///   has not yet detected an expiration.
+    pub fn next_expired_with_priority(&self, cursor: &mut CursorT<T>) -> Option<(u32, u8)> {
+        self.next_expired_node(cursor)
+            .map(|node| (node.id, node.priority))
+    }
+
+    /// Raw-pointer variant of
+    /// [`next_expired_with_priority`](Self::next_expired_with_priority).
+    #[deprecated(note = "use `next_expired_with_priority` with a `Cursor`")]
+    pub fn next_expired_with_priority_raw(
+        &self,
+        cursor: &mut *const WatchdogNodeT<T>,
+    ) -> Option<(u32, u8)> {
+        with_raw_cursor(cursor, |cursor| self.next_expired_with_priority(cursor))
+    }
+
+    /// Collect the ids of expired watchdogs into `out`.
+    ///
+    /// Convenience over the [`next_expired`](Self::next_expired) cursor
+    /// loop, with the same evaluation: nodes are compared against the
+    /// timestamp captured by [`check`](Self::check), and nothing is reported
+    /// until `check` has detected an expiration. Ids are written in list
+    /// order; once `out` is full the remaining expired nodes are skipped.
+    ///
+    /// # Parameters
+    /// - `out`: buffer receiving the expired ids.
+    ///
+    /// # Returns
+    /// The number of ids written to `out`.
+    pub fn expired_ids(&self, out: &mut [u32]) -> usize {
+        let mut cursor = CursorT::start();
+        out.iter_mut()
+            .zip(core::iter::from_fn(|| self.next_expired(&mut cursor)))
+            .map(|(slot, id)| *slot = id)
+            .count()
+    }
+
+    /// Get the next expired watchdog node in the iteration, returning the
+    /// node itself instead of only its id.
+    ///
+    /// Behaves exactly like [`next_expired`](Self::next_expired) (same
+    /// cursor protocol, same timestamp snapshot and half-range guard),
+    /// but yields a pinned shared reference so the caller can read the
+    /// node's configuration, e.g. to build a detailed fault report. The
+    /// reference borrows the registry, so the list cannot be modified while
+    /// it is held.
+    ///
+    /// # Parameters
+    /// - `cursor`: iteration state, see [`next_expired`](Self::next_expired).
+    ///
+    /// # Returns
+    /// - `Some(node)` if an expired node was found.
+    /// - `None` when no more expired nodes remain, or if [`check`](Self::check)
+    ///   has not yet detected an expiration.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use mwdg::{Cursor, WatchdogRegistry};
+    /// # let mut registry = WatchdogRegistry::new();
+    /// # let now = 0u32;
+    /// if registry.check(now) {
+    ///     let mut cursor = Cursor::start();
+    ///     while let Some(node) = registry.next_expired_node(&mut cursor) {
+    ///         let (id, timeout) = (node.id(), node.timeout_interval_ms());
+    ///         // report watchdog `id` with its configured `timeout`
+    ///     }
+    /// }
+    /// ```
+    pub fn next_expired_node(&self, cursor: &mut CursorT<T>) -> Option<Pin<&WatchdogNodeT<T>>> {
+        if !self.expired {
+            return None;
+        }
+
+        let now = self.scanned_at_ms;
+
+        let mut current = self.cursor_start(*cursor);
+        while !current.is_null() {
+            // SAFETY: `current` is non-null and points to a valid, pinned
+            // node in the list. We only read fields.
+            let node = unsafe { &*current };
+            // Nodes fed *after* the `scanned_at_ms` snapshot was taken are
+            // "in the future" and filtered out by `since_feed`.
+            let elapsed = self.since_feed(node, now);
+
+            if self.is_past_timeout_since(node, elapsed) {
+                self.advance(cursor, current);
+                // SAFETY: every node in the list is pinned by API contract;
+                // the returned reference borrows `self`, so the node stays
+                // linked (and therefore alive) for its whole lifetime.
+                return Some(unsafe { Pin::new_unchecked(node) });
+            }
+
+            current = node.next.cast_const();
+        }
+
+        None
+    }
+
+    /// Raw-pointer variant of [`next_expired_node`](Self::next_expired_node).
+    #[deprecated(note = "use `next_expired_node` with a `Cursor`")]
+    pub fn next_expired_node_raw(
+        &self,
+        cursor: &mut *const WatchdogNodeT<T>,
+    ) -> Option<Pin<&WatchdogNodeT<T>>> {
+        with_raw_cursor(cursor, |cursor| self.next_expired_node(cursor))
+    }
+
+    /// Get the next registered watchdog node in the iteration, regardless of
+    /// its health.
+    ///
+    /// Same cursor protocol as [`next_expired`](Self::next_expired), but
+    /// every node is yielded in list order and no prior
+    /// [`check`](Self::check) is required.
+    ///
+    /// # Parameters
+    /// - `cursor`: iteration state, see [`next_expired`](Self::next_expired).
+    ///
+    /// # Returns
+    /// - `Some(node)` for the next registered node.
+    /// - `None` once the end of the list is reached.
+    pub fn next_node(&self, cursor: &mut CursorT<T>) -> Option<Pin<&WatchdogNodeT<T>>> {
+        let current = self.cursor_start(*cursor);
+        if current.is_null() {
+            return None;
+        }
+
+        self.advance(cursor, current);
+        // SAFETY: `current` is non-null and points to a valid node in the
+        // list; every node in the list is pinned by API contract and the
+        // returned reference borrows `self`.
+        Some(unsafe { Pin::new_unchecked(&*current) })
+    }
+
+    /// Raw-pointer variant of [`next_node`](Self::next_node).
+    #[deprecated(note = "use `next_node` with a `Cursor`")]
+    pub fn next_node_raw(
+        &self,
+        cursor: &mut *const WatchdogNodeT<T>,
+    ) -> Option<Pin<&WatchdogNodeT<T>>> {
+        with_raw_cursor(cursor, |cursor| self.next_node(cursor))
+    }
+}
+
+/// Prints the number of registered nodes, the nodes themselves and the
+/// expiration state.
+impl<T: WrappingTime + fmt::Debug> fmt::Debug for WatchdogRegistryT<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WatchdogRegistry")
+            .field("len", &self.iter_ids().count())
+            .field("nodes", &NodeList(self))
+            .field("expired", &self.expired)
+            .field("expired_at_ms", &self.expired_at_ms)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Debug adapter listing the nodes of a registry.
+struct NodeList<'a, T: WrappingTime>(&'a WatchdogRegistryT<T>);
+
+impl<T: WrappingTime + fmt::Debug> fmt::Debug for NodeList<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0).finish()
+    }
+}
+
+/// Registration guard returned by [`WatchdogRegistryT::register`].
+///
+/// Dropping the guard removes its node from the registry, so a node pinned
+/// on the stack cannot be left dangling in the list when its frame ends.
+///
+/// # Lifetimes
+///
+/// The guard holds both the `&'r mut` registry borrow and the
+/// `Pin<&'r mut>` node borrow for the same lifetime `'r`. The borrow
+/// checker therefore rejects dropping or moving either the registry or the
+/// node while the guard is alive, and the guard's `Drop` always runs
+/// against a live registry and a live node. Because the registry is
+/// borrowed exclusively, it stays reachable only through
+/// [`registry`](Self::registry) until the guard is dropped — this suits a
+/// single task that owns both, not a registry shared between tasks.
+///
+/// Leaking the guard (e.g. with [`core::mem::forget`]) skips the removal
+/// and leaves the node linked, exactly like forgetting to call
+/// [`remove`](WatchdogRegistryT::remove).
+///
+/// ```rust
+/// use mwdg::{WatchdogNode, WatchdogRegistry};
+/// use core::pin::pin;
+///
+/// let mut registry = WatchdogRegistry::new();
+/// {
+///     let node = pin!(WatchdogNode::default());
+///     let mut guard = registry.register(node, 100, 0);
+///     guard.feed(50);
+///     assert!(!guard.registry().check(120));
+/// } // node removed here
+/// assert_eq!(registry.iter_ids().count(), 0);
+/// ```
+///
+/// The guard cannot outlive the registry:
+///
+/// ```compile_fail
+/// use mwdg::{WatchdogNode, WatchdogRegistry};
+/// use core::pin::pin;
+///
+/// let node = pin!(WatchdogNode::default());
+/// let guard = {
+///     let mut registry = WatchdogRegistry::new();
+///     registry.register(node, 100, 0) // `registry` does not live long enough
+/// };
+/// ```
+pub struct WatchdogGuard<'r, T: WrappingTime> {
+    registry: &'r mut WatchdogRegistryT<T>,
+    node: Pin<&'r mut WatchdogNodeT<T>>,
+}
+
+impl<T: WrappingTime> WatchdogGuard<'_, T> {
+    /// Feed the guarded node, see [`WatchdogRegistryT::feed`].
+    ///
+    /// # Parameters
+    /// - `now`: the current timestamp in milliseconds.
+    pub fn feed(&mut self, now: T) {
+        WatchdogRegistryT::feed(self.node.as_mut(), now);
+    }
+
+    /// Returns the guarded node.
+    #[must_use]
+    pub fn node(&self) -> Pin<&WatchdogNodeT<T>> {
+        self.node.as_ref()
+    }
+
+    /// Returns the registry the node is registered with, e.g. to run
+    /// [`check`](WatchdogRegistryT::check) while the guard is alive.
+    pub fn registry(&mut self) -> &mut WatchdogRegistryT<T> {
+        self.registry
+    }
+}
+
+impl<T: WrappingTime> Drop for WatchdogGuard<'_, T> {
+    fn drop(&mut self) {
+        self.registry.remove(self.node.as_mut());
+    }
+}
+
+/// Iterator over the registered nodes, returned by
+/// [`WatchdogRegistryT::iter`].
+pub struct Iter<'a, T: WrappingTime> {
+    /// Next node to visit; null once the tail has been passed.
+    current: *const WatchdogNodeT<T>,
+    /// Ties the iterator to a shared borrow of the registry.
+    _registry: PhantomData<&'a WatchdogRegistryT<T>>,
+}
+
+impl<'a, T: WrappingTime> Iterator for Iter<'a, T> {
+    type Item = Pin<&'a WatchdogNodeT<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current.is_null() {
+            return None;
+        }
+
+        // SAFETY: `current` is non-null and points to a valid, pinned node in
+        // the list. The registry is borrowed for `'a`, so the list cannot be
+        // modified and the node stays linked (and alive) while we walk it.
+        let node = unsafe { &*self.current };
+        self.current = node.next.cast_const();
+
+        // SAFETY: every node in the list is pinned by API contract.
+        Some(unsafe { Pin::new_unchecked(node) })
+    }
+}
+
+impl<'a, T: WrappingTime> IntoIterator for &'a WatchdogRegistryT<T> {
+    type Item = Pin<&'a WatchdogNodeT<T>>;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// Draining iterator over the registered nodes, returned by
+/// [`WatchdogRegistryT::drain`].
+pub struct Drain<'a, T: WrappingTime> {
+    registry: &'a mut WatchdogRegistryT<T>,
+}
+
+impl<'a, T: WrappingTime> Iterator for Drain<'a, T> {
+    type Item = Pin<&'a mut WatchdogNodeT<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let head = self.registry.head;
+        if head.is_null() {
+            return None;
+        }
+
+        // SAFETY: `head` is the first node of the list, so it has no
+        // predecessor.
+        unsafe {
+            self.registry.unlink(ptr::null_mut(), head);
+        }
+
+        // SAFETY: `head` is a valid node that was pinned when it was added.
+        // It has just been unlinked, so the registry no longer reaches it
+        // and this is the only reference the drain hands out for it.
+        Some(unsafe { Pin::new_unchecked(&mut *head) })
+    }
+}
+
+impl<T: WrappingTime> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+/// A [`NodeHandleT`] for `u32` millisecond timestamps.
+pub type NodeHandle = NodeHandleT<u32>;
+
+/// A raw pointer to a watchdog node that may be sent to another thread.
+///
+/// Raw pointers are `!Send`, so moving a node pointer into a spawned
+/// thread or task otherwise requires a hand-written wrapper with its own
+/// `unsafe impl Send`. This is the vetted version of that wrapper: the
+/// obligations are stated once, on [`new`](Self::new).
+///
+/// ```rust
+/// use core::pin::Pin;
+/// use mwdg::{NodeHandle, WatchdogNode, WatchdogRegistry};
+///
+/// // A node that lives for the rest of the program.
+/// let node: &'static mut WatchdogNode = Box::leak(Box::new(WatchdogNode::default()));
+/// // SAFETY: the node is leaked, so it stays valid and never moves, and
+/// // only the spawned thread accesses it from now on.
+/// let handle = unsafe { NodeHandle::new(node) };
+///
+/// std::thread::spawn(move || {
+///     // SAFETY: see above.
+///     let node = unsafe { Pin::new_unchecked(&mut *handle.get()) };
+///     WatchdogRegistry::feed(node, 42);
+/// })
+/// .join()
+/// .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeHandleT<T: WrappingTime>(*mut WatchdogNodeT<T>);
+
+// SAFETY: `NodeHandleT` is just an address. The obligations for using it
+// from another thread (the node stays valid and pinned, and accesses are
+// synchronized) are accepted by the caller of `NodeHandleT::new`.
+unsafe impl<T: WrappingTime> Send for NodeHandleT<T> {}
+
+impl<T: WrappingTime> NodeHandleT<T> {
+    /// Wrap a node pointer so it can be moved across threads.
+    ///
+    /// # Safety
+    /// For as long as the handle (or any copy of it) is used, `node` must
+    /// stay valid and must not be moved, and every access through
+    /// [`get`](Self::get) must be synchronized with other accesses to the
+    /// node, e.g. by the lock or critical section guarding its registry.
+    #[must_use]
+    pub const unsafe fn new(node: *mut WatchdogNodeT<T>) -> Self {
+        Self(node)
+    }
+
+    /// Returns the wrapped node pointer.
+    #[must_use]
+    pub const fn get(self) -> *mut WatchdogNodeT<T> {
+        self.0
+    }
+}
+
+/// A [`CursorT`] for `u32` millisecond timestamps.
+pub type Cursor = CursorT<u32>;
+
+/// Opaque iteration state for the cursor-based iterators of
+/// [`WatchdogRegistryT`], such as [`next_expired`](WatchdogRegistryT::next_expired)
+/// and [`next_node`](WatchdogRegistryT::next_node).
+///
+/// Remembers the last reported node without exposing its address, so
+/// callers cannot forge an arbitrary position. Start every iteration with
+/// [`start`](Self::start).
+///
+/// Resuming is O(1) as long as the cursor is used with the registry that
+/// advanced it and no node was unlinked or relinked in between. Otherwise,
+/// e.g. for a cursor built with [`from_raw`](Self::from_raw), the registry
+/// first checks that the node is still linked, which costs a walk of the
+/// list.
+///
+/// A cursor must not outlive the registry that advanced it. The registry
+/// is recognised by its address, so a cursor kept after its registry was
+/// dropped or moved could be trusted by another registry later placed at
+/// the same address. Use [`reset`](Self::reset) or a fresh cursor instead.
+///
+/// ```rust
+/// use mwdg::{Cursor, WatchdogRegistry};
+///
+/// let registry = WatchdogRegistry::new();
+/// let mut cursor = Cursor::start();
+/// while let Some(id) = registry.next_expired(&mut cursor) {
+///     // handle expired watchdog `id`
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CursorT<T: WrappingTime> {
+    node: *const WatchdogNodeT<T>,
+    registry: *const WatchdogRegistryT<T>,
+    generation: usize,
+}
+
+impl<T: WrappingTime> Default for CursorT<T> {
+    fn default() -> Self {
+        Self::start()
+    }
+}
+
+impl<T: WrappingTime> CursorT<T> {
+    /// A cursor positioned before the head of the list.
+    #[must_use]
+    pub const fn start() -> Self {
+        Self::from_raw(ptr::null())
+    }
+
+    /// Rewind the cursor so the next iteration restarts from the head,
+    /// like [`WatchdogRegistryT::reset_cursor`] for raw cursors.
+    pub fn reset(&mut self) {
+        *self = Self::start();
+    }
+
+    /// Resume from a raw node pointer, e.g. one kept by C code between
+    /// calls.
+    ///
+    /// Meant for FFI adapters. The registry validates the pointer before
+    /// following it: one that is not linked into the list restarts the
+    /// iteration from the head instead of being dereferenced. That check
+    /// walks the list, so resuming such a cursor is O(n).
+    #[must_use]
+    pub const fn from_raw(node: *const WatchdogNodeT<T>) -> Self {
+        Self {
+            node,
+            registry: ptr::null(),
+            generation: 0,
+        }
+    }
+
+    /// Returns the position as a raw node pointer, see
+    /// [`from_raw`](Self::from_raw).
+    #[must_use]
+    pub const fn into_raw(self) -> *const WatchdogNodeT<T> {
+        self.node
+    }
+}
+
+/// Run a cursor-based iterator on a raw cursor, for the deprecated `_raw`
+/// variants.
+fn with_raw_cursor<T: WrappingTime, R>(
+    cursor: &mut *const WatchdogNodeT<T>,
+    f: impl FnOnce(&mut CursorT<T>) -> R,
+) -> R {
+    let mut wrapped = CursorT::from_raw(*cursor);
+    let result = f(&mut wrapped);
+    *cursor = wrapped.into_raw();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use core::ptr;
+    use std::format;
+
+    /// Helper: create a pinned mutable reference from a mutable reference.
+    ///
+    /// # Safety
+    /// The caller must not move the referenced value after calling this.
+    /// In tests we own the nodes on the stack and never move them, so this
+    /// is safe.
+    unsafe fn pin_mut<T: WrappingTime>(node: &mut WatchdogNodeT<T>) -> Pin<&mut WatchdogNodeT<T>> {
+        unsafe { Pin::new_unchecked(node) }
+    }
+
+    /// Helper: create a pinned shared reference from a shared reference.
+    ///
+    /// # Safety
+    /// Same contract as [`pin_mut`].
+    unsafe fn pin_ref<T: WrappingTime>(node: &WatchdogNodeT<T>) -> Pin<&WatchdogNodeT<T>> {
+        unsafe { Pin::new_unchecked(node) }
+    }
+
+    /// Helper: count nodes reachable from `head`.
+    fn count_nodes<T: WrappingTime>(head: *const WatchdogNodeT<T>) -> u32 {
+        let mut count = 0u32;
+        let mut current = head;
+        while !current.is_null() {
+            count += 1;
+            // SAFETY: `current` is non-null and points to a valid node.
+            current = unsafe { (*current).next.cast_const() };
+        }
+        count
+    }
+
+    #[test]
+    fn test_add_single_node() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe { reg.add(pin_mut(&mut n), 100, 0) };
+
+        assert_eq!(count_nodes(reg.head), 1);
+        assert_eq!(n.timeout_interval_ms, 100);
+        assert_eq!(n.last_touched_timestamp_ms, 0);
+    }
+
+    #[test]
+    fn test_add_multiple_nodes() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+        let mut n3 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
+            reg.add(pin_mut(&mut n3), 300, 0);
+        }
+
+        assert_eq!(count_nodes(reg.head), 3);
+        // Registration order: head -> n1 -> n2 -> n3 <- tail
+        assert_eq!(reg.head, &mut n1 as *mut WatchdogNode);
+        assert_eq!(reg.tail, &mut n3 as *mut WatchdogNode);
+    }
+
+    #[test]
+    fn test_add_duplicate_acts_as_feed() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 10);
+        }
+        assert_eq!(n.last_touched_timestamp_ms, 10);
+        assert_eq!(n.timeout_interval_ms, 100);
+
+        // Re-add with new timeout and timestamp
+        unsafe {
+            reg.add(pin_mut(&mut n), 250, 50);
+        }
+        assert_eq!(n.last_touched_timestamp_ms, 50);
+        assert_eq!(n.timeout_interval_ms, 250);
+        // Should still be just one node
+        assert_eq!(count_nodes(reg.head), 1);
+    }
+
+    #[test]
+    fn test_try_add_rejects_duplicate() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            assert_eq!(reg.try_add(pin_mut(&mut n), 100, 10), Ok(()));
+            assert_eq!(
+                reg.try_add(pin_mut(&mut n), 250, 50),
+                Err(WatchdogError::AlreadyRegistered)
+            );
+        }
+        // The failed call leaves the node untouched.
+        assert_eq!(n.timeout_interval_ms, 100);
+        assert_eq!(n.last_touched_timestamp_ms, 10);
+        assert_eq!(count_nodes(reg.head), 1);
+    }
+
+    #[test]
+    fn test_watchdog_error_display() {
+        assert_eq!(
+            format!("{}", WatchdogError::AlreadyRegistered),
+            "watchdog node is already registered"
+        );
+        assert_eq!(
+            format!("{}", WatchdogError::Full),
+            "watchdog registry is full"
+        );
+        assert_eq!(
+            format!("{}", WatchdogError::ZeroTimeout),
+            "watchdog timeout is zero"
+        );
+    }
+
+    #[test]
+    fn test_watchdog_error_propagates_with_question_mark() {
+        #[derive(Debug, PartialEq)]
+        enum AppError {
+            Watchdog(WatchdogError),
+        }
+
+        impl From<WatchdogError> for AppError {
+            fn from(err: WatchdogError) -> Self {
+                Self::Watchdog(err)
+            }
+        }
+
+        fn register(
+            reg: &mut WatchdogRegistry,
+            node: Pin<&mut WatchdogNode>,
+            timeout_ms: u32,
+        ) -> Result<(), AppError> {
+            reg.try_add(node, timeout_ms, 0)?;
+            Ok(())
+        }
+
+        fn register_boxed(
+            reg: &mut WatchdogRegistry,
+            node: Pin<&mut WatchdogNode>,
+        ) -> Result<(), std::boxed::Box<dyn core::error::Error>> {
+            reg.try_add(node, 0, 0)?;
+            Ok(())
+        }
+
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            assert_eq!(register(&mut reg, pin_mut(&mut n), 100), Ok(()));
+            assert_eq!(
+                register(&mut reg, pin_mut(&mut n), 100),
+                Err(AppError::Watchdog(WatchdogError::AlreadyRegistered))
+            );
+            let err = register_boxed(&mut reg, pin_mut(&mut n)).unwrap_err();
+            assert_eq!(format!("{err}"), "watchdog timeout is zero");
+        }
+    }
+
+    #[test]
+    fn test_add_preserves_user_id() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            WatchdogRegistry::assign_id(pin_mut(&mut n), 42);
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert_eq!(n.id, 42, "add must not overwrite a pre-set id");
+    }
+
+    #[test]
+    fn test_readd_preserves_user_id() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            WatchdogRegistry::assign_id(pin_mut(&mut n), 7);
+            reg.add(pin_mut(&mut n), 100, 0);
+            reg.add(pin_mut(&mut n), 200, 50);
+        }
+        assert_eq!(n.id, 7, "re-add must not overwrite the id field");
+    }
+
+    #[test]
+    fn test_builder_presets_fields() {
+        let n = WatchdogNode::builder()
+            .id(3)
+            .timeout_ms(200)
+            .warn_interval_ms(150)
+            .build();
+
+        assert_eq!(n.id, 3);
+        assert_eq!(n.timeout_interval_ms, 200);
+        assert_eq!(n.warn_interval_ms, 150);
+        assert_eq!(n.last_touched_timestamp_ms, 0);
+        assert!(n.next.is_null());
+    }
+
+    #[test]
+    fn test_add_configured_keeps_preset_timeout() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(3).timeout_ms(200).build();
+
+        unsafe {
+            reg.add_configured(pin_mut(&mut n), 50);
+        }
+        assert_eq!(n.timeout_interval_ms, 200);
+        assert_eq!(n.last_touched_timestamp_ms, 50);
+        assert_eq!(n.id, 3);
+        assert_eq!(count_nodes(reg.head), 1);
+
+        assert!(!reg.check(250));
+        assert!(reg.check(251));
+    }
+
+    #[test]
+    fn test_debug_node() {
+        let n = WatchdogNode::builder().id(9).timeout_ms(200).build();
+
+        let s = format!("{n:?}");
+        assert!(s.contains("id: 9"), "{s}");
+        assert!(s.contains("timeout_interval_ms: 200"), "{s}");
+        assert!(!s.contains("next"), "{s}");
+    }
+
+    #[test]
+    fn test_debug_registry() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(11).build();
+        let mut n2 = WatchdogNode::builder().id(22).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
+        }
+        assert!(reg.check(150));
+
+        let s = format!("{reg:?}");
+        assert!(s.contains("len: 2"), "{s}");
+        assert!(s.contains("id: 11"), "{s}");
+        assert!(s.contains("id: 22"), "{s}");
+        assert!(s.contains("expired: true"), "{s}");
+        assert!(s.contains("expired_at_ms: 150"), "{s}");
+    }
+
+    #[test]
+    fn test_guard_drop_unlinks_node() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+        }
+        {
+            let mut guard = reg.register(unsafe { pin_mut(&mut n2) }, 100, 0);
+            assert_eq!(guard.node().id(), 2);
+            assert_eq!(count_nodes(guard.registry().head), 2);
+
+            guard.feed(80);
+            assert!(!guard.registry().check(100));
+        }
+
+        assert_eq!(count_nodes(reg.head), 1);
+        assert_eq!(reg.head, &mut n1 as *mut WatchdogNode);
+        assert_eq!(reg.tail, &mut n1 as *mut WatchdogNode);
+        assert!(n2.next.is_null());
+        assert_eq!(n2.last_touched_timestamp_ms, 80);
+    }
+
+    #[test]
+    fn test_remove_single_node() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert_eq!(count_nodes(reg.head), 1);
+
+        unsafe {
+            reg.remove(pin_mut(&mut n));
+        }
+        assert_eq!(count_nodes(reg.head), 0);
+        assert!(n.next.is_null());
+    }
+
+    #[test]
+    fn test_remove_head() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
+        }
+        // head -> n1 -> n2
+        assert_eq!(count_nodes(reg.head), 2);
+
+        unsafe {
+            reg.remove(pin_mut(&mut n1));
+        }
+        assert_eq!(count_nodes(reg.head), 1);
+        assert_eq!(reg.head, &mut n2 as *mut WatchdogNode);
+        assert_eq!(reg.tail, &mut n2 as *mut WatchdogNode);
+    }
+
+    #[test]
+    fn test_remove_from_middle() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+        let mut n3 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
+            reg.add(pin_mut(&mut n3), 300, 0);
+        }
+        // head -> n1 -> n2 -> n3
+        assert_eq!(count_nodes(reg.head), 3);
+
+        unsafe {
+            reg.remove(pin_mut(&mut n2));
+        }
+        assert_eq!(count_nodes(reg.head), 2);
+        assert!(n2.next.is_null());
+        // n1 -> n3
+        assert_eq!(reg.head, &mut n1 as *mut WatchdogNode);
+        assert_eq!(n1.next, &mut n3 as *mut WatchdogNode);
+        assert_eq!(reg.tail, &mut n3 as *mut WatchdogNode);
+    }
+
+    #[test]
+    fn test_remove_tail_updates_tail() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+        let mut n3 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
+            reg.remove(pin_mut(&mut n2));
+        }
+        assert_eq!(reg.tail, &mut n1 as *mut WatchdogNode);
+        assert!(n1.next.is_null());
+
+        // Appending after removing the tail must link from the new tail.
+        unsafe {
+            reg.add(pin_mut(&mut n3), 300, 0);
+        }
+        assert_eq!(count_nodes(reg.head), 2);
+        assert_eq!(n1.next, &mut n3 as *mut WatchdogNode);
+        assert_eq!(reg.tail, &mut n3 as *mut WatchdogNode);
+
+        unsafe {
+            reg.remove(pin_mut(&mut n3));
+            reg.remove(pin_mut(&mut n1));
+        }
+        assert!(reg.head.is_null());
+        assert!(reg.tail.is_null());
+
+        // The emptied list accepts new nodes again.
+        unsafe {
+            reg.add(pin_mut(&mut n2), 200, 0);
+        }
+        assert_eq!(reg.head, &mut n2 as *mut WatchdogNode);
+        assert_eq!(reg.tail, &mut n2 as *mut WatchdogNode);
+    }
+
+    #[test]
+    fn test_remove_unchecked_head_middle_tail() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+        let mut n4 = WatchdogNode::builder().id(4).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            reg.add(pin_mut(&mut n3), 100, 0);
+            reg.add(pin_mut(&mut n4), 100, 0);
+        }
+        assert_eq!(n3.prev, &mut n2 as *mut WatchdogNode);
+
+        // Middle: n1 -> n3 -> n4
+        unsafe {
+            reg.remove_unchecked(pin_mut(&mut n2));
+        }
+        assert!(n2.next.is_null() && n2.prev.is_null() && !n2.is_linked());
+        assert_eq!(n1.next, &mut n3 as *mut WatchdogNode);
+        assert_eq!(n3.prev, &mut n1 as *mut WatchdogNode);
+        assert!(reg.verify_integrity(4));
+
+        // Head: n3 -> n4
+        unsafe {
+            reg.remove_unchecked(pin_mut(&mut n1));
+        }
+        assert_eq!(reg.head, &mut n3 as *mut WatchdogNode);
+        assert!(n3.prev.is_null());
+        assert!(reg.verify_integrity(4));
+
+        // Tail: n3
+        unsafe {
+            reg.remove_unchecked(pin_mut(&mut n4));
+        }
+        assert_eq!(reg.tail, &mut n3 as *mut WatchdogNode);
+        assert!(n3.next.is_null());
+        assert!(reg.verify_integrity(4));
+
+        // Last node: empty list, and re-adding links from scratch.
+        unsafe {
+            reg.remove_unchecked(pin_mut(&mut n3));
+            reg.add(pin_mut(&mut n2), 100, 0);
+        }
+        assert_eq!(reg.head, &mut n2 as *mut WatchdogNode);
+        assert_eq!(reg.tail, &mut n2 as *mut WatchdogNode);
+        assert!(reg.verify_integrity(4));
+    }
+
+    #[test]
+    fn test_back_links_survive_relinking() {
+        let mut reg = WatchdogRegistry::new();
+        let mut other = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+        let mut n4 = WatchdogNode::builder().id(4).build();
+
+        reg.set_feed_to_tail(true);
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            other.add(pin_mut(&mut n3), 100, 0);
+        }
+        assert!(reg.feed_by_id(1, 10));
+        assert!(reg.verify_integrity(4));
+
+        assert_eq!(reg.merge(&mut other), Ok(()));
+        assert_eq!(n3.prev, &mut n1 as *mut WatchdogNode);
+        assert!(reg.verify_integrity(4));
+
+        assert!(reg.replace(
+            unsafe { pin_mut(&mut n1) },
+            unsafe { pin_mut(&mut n4) },
+            100,
+            20
+        ));
+        assert!(n1.prev.is_null());
+        assert_eq!(n4.prev, &mut n2 as *mut WatchdogNode);
+        assert_eq!(n3.prev, &mut n4 as *mut WatchdogNode);
+        assert!(reg.verify_integrity(4));
+    }
+
+    #[test]
+    fn test_verify_integrity_detects_stale_back_link() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+        }
+        // Break n2's back link while keeping the forward links intact.
+        unsafe {
+            (*reg.tail).prev = ptr::null_mut();
+        }
+        assert!(!reg.verify_integrity(2));
+    }
+
+    #[test]
+    fn test_remove_not_found_is_noop() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+        }
+        // Try removing a node that was never added
+        unsafe {
+            reg.remove(pin_mut(&mut n2));
+        }
+        assert_eq!(count_nodes(reg.head), 1);
+    }
+
+    #[test]
+    fn test_remove_idempotent() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+        let mut n3 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
+            reg.add(pin_mut(&mut n3), 300, 0);
+        }
+
+        // Remove n3 three times — should not corrupt the list
+        unsafe {
+            reg.remove(pin_mut(&mut n3));
+            reg.remove(pin_mut(&mut n3));
+            reg.remove(pin_mut(&mut n3));
+        }
+        assert_eq!(count_nodes(reg.head), 2);
+
+        // Remove n1 three times
+        unsafe {
+            reg.remove(pin_mut(&mut n1));
+            reg.remove(pin_mut(&mut n1));
+            reg.remove(pin_mut(&mut n1));
+        }
+        assert_eq!(count_nodes(reg.head), 1);
+
+        // Remove n2 three times
+        unsafe {
+            reg.remove(pin_mut(&mut n2));
+            reg.remove(pin_mut(&mut n2));
+            reg.remove(pin_mut(&mut n2));
+        }
+        assert_eq!(count_nodes(reg.head), 0);
+    }
+
+    #[test]
+    fn test_remove_all() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+        let mut n3 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
+            reg.add(pin_mut(&mut n3), 300, 0);
+        }
+        assert!(reg.check(150));
+
+        reg.remove_all();
+
+        assert_eq!(count_nodes(reg.head), 0);
+        assert!(reg.tail.is_null());
+        assert!(n1.next.is_null());
+        assert!(n2.next.is_null());
+        assert!(n3.next.is_null());
+        // The latch is not touched.
+        assert!(reg.is_expired());
+        assert_eq!(reg.expired_at_ms, 150);
+
+        // Nodes can be registered again.
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 200);
+        }
+        assert_eq!(count_nodes(reg.head), 1);
+    }
+
+    #[test]
+    fn test_contains_registered_node() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
+        }
+        assert!(reg.contains(unsafe { pin_ref(&n1) }));
+        assert!(reg.contains(unsafe { pin_ref(&n2) }));
+    }
+
+    #[test]
+    fn test_contains_never_added() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let n2 = WatchdogNode::default();
+
+        assert!(!reg.contains(unsafe { pin_ref(&n2) }));
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+        }
+        assert!(!reg.contains(unsafe { pin_ref(&n2) }));
+    }
+
+    #[test]
+    fn test_contains_after_remove() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+            reg.remove(pin_mut(&mut n));
+        }
+        assert!(!reg.contains(unsafe { pin_ref(&n) }));
+    }
+
+    #[test]
+    fn test_contains_id() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        assert!(!reg.contains_id(1));
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+        }
+        assert!(reg.contains_id(1));
+        assert!(reg.contains_id(2));
+        assert!(!reg.contains_id(3));
+
+        unsafe {
+            reg.remove(pin_mut(&mut n1));
+        }
+        assert!(!reg.contains_id(1));
+    }
+
+    #[test]
+    fn test_contains_id_shared_by_two_nodes() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(7).build();
+        let mut n2 = WatchdogNode::builder().id(7).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+        }
+        assert!(reg.contains_id(7));
+
+        // Still found while one of the two remains registered.
+        unsafe {
+            reg.remove(pin_mut(&mut n1));
+        }
+        assert!(reg.contains_id(7));
+    }
+
+    #[test]
+    fn test_iter_ids_order() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+        let mut n3 = WatchdogNode::default();
+
+        unsafe {
+            WatchdogRegistry::assign_id(pin_mut(&mut n1), 1);
+            WatchdogRegistry::assign_id(pin_mut(&mut n2), 2);
+            WatchdogRegistry::assign_id(pin_mut(&mut n3), 3);
+
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
+            reg.add(pin_mut(&mut n3), 300, 0);
+        }
+
+        // Registration order: head -> n1 -> n2 -> n3, regardless of health.
+        let mut ids = [0u32; 4];
+        let mut count = 0;
+        for id in reg.iter_ids() {
+            ids[count] = id;
+            count += 1;
+        }
+        assert_eq!(count, 3);
+        assert_eq!(ids[..count], [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_into_iterator_yields_nodes() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 250, 0);
+        }
+
+        let mut seen = [(0u32, 0u32); 4];
+        let mut count = 0;
+        for node in &reg {
+            seen[count] = (node.id(), node.timeout_interval_ms());
+            count += 1;
+        }
+        assert_eq!(seen[..count], [(1, 100), (2, 250)]);
+        assert_eq!(reg.iter().count(), 2);
+    }
+
+    #[test]
+    fn test_iter_ids_empty() {
+        let reg = WatchdogRegistry::new();
+        assert_eq!(reg.iter_ids().next(), None);
+    }
+
+    #[test]
+    fn test_feed_updates_timestamp() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 500, 100);
+        }
+        assert_eq!(n.last_touched_timestamp_ms, 100);
+
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 350);
+        }
+        assert_eq!(n.last_touched_timestamp_ms, 350);
+    }
+
+    #[test]
+    fn test_feed_preserves_user_id() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            WatchdogRegistry::assign_id(pin_mut(&mut n), 13);
+            reg.add(pin_mut(&mut n), 100, 0);
+            WatchdogRegistry::feed(pin_mut(&mut n), 50);
+        }
+        assert_eq!(n.id, 13, "feed must not overwrite the id field");
+    }
+
+    #[test]
+    fn test_feed_by_id_match() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            WatchdogRegistry::assign_id(pin_mut(&mut n1), 1);
+            WatchdogRegistry::assign_id(pin_mut(&mut n2), 2);
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+        }
+
+        assert!(reg.feed_by_id(1, 80));
+        assert_eq!(n1.last_touched_timestamp_ms, 80);
+        assert_eq!(n2.last_touched_timestamp_ms, 0);
+    }
+
+    #[test]
+    fn test_feed_by_id_missing() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            WatchdogRegistry::assign_id(pin_mut(&mut n), 1);
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+
+        assert!(!reg.feed_by_id(7, 80));
+        assert_eq!(n.last_touched_timestamp_ms, 0);
+    }
+
+    #[test]
+    fn test_feed_by_id_duplicate_feeds_first_only() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            WatchdogRegistry::assign_id(pin_mut(&mut n1), 5);
+            WatchdogRegistry::assign_id(pin_mut(&mut n2), 5);
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+        }
+        // list: n1 -> n2
+
+        assert!(reg.feed_by_id(5, 80));
+        assert_eq!(n1.last_touched_timestamp_ms, 80);
+        assert_eq!(n2.last_touched_timestamp_ms, 0);
+    }
+
+    #[test]
+    fn test_set_timeout_keeps_timestamp() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 500, 100);
+        }
+        assert!(!reg.check(250));
+
+        // Tighten the timeout: the old timestamp (100) still applies.
+        unsafe {
+            WatchdogRegistry::set_timeout(pin_mut(&mut n), 100);
+        }
+        assert_eq!(n.timeout_interval_ms, 100);
+        assert_eq!(n.last_touched_timestamp_ms, 100);
+        assert!(reg.check(250));
+    }
+
+    #[test]
+    fn test_feed_checked_registered() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+            assert!(reg.feed_checked(pin_mut(&mut n), 80));
+        }
+        assert_eq!(n.last_touched_timestamp_ms, 80);
+    }
+
+    #[test]
+    fn test_feed_checked_unregistered() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            assert!(!reg.feed_checked(pin_mut(&mut n), 80));
+        }
+        assert_eq!(n.last_touched_timestamp_ms, 0);
+
+        // Feeding after removal is reported too.
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 10);
+            reg.remove(pin_mut(&mut n));
+            assert!(!reg.feed_checked(pin_mut(&mut n), 90));
+        }
+        assert_eq!(n.last_touched_timestamp_ms, 10);
+    }
+
+    #[test]
+    fn test_paused_node_never_expires() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            WatchdogRegistry::pause(pin_mut(&mut n1));
+        }
+        assert!(n1.is_paused());
+
+        assert!(!reg.check(100));
+        assert_eq!(reg.check_all(100), 0);
+        assert_eq!(reg.worst_margin(50), Some((2, 50)));
+
+        // Only the unpaused node is reported once it expires.
+        assert!(reg.check(150));
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(2));
+        assert_eq!(reg.next_expired(&mut cursor), None);
+        assert_eq!(count_nodes(reg.head), 2);
+    }
+
+    #[test]
+    fn test_resumed_node_counts_from_resume_time() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+            WatchdogRegistry::pause(pin_mut(&mut n));
+        }
+        assert!(!reg.check(5000));
+
+        unsafe {
+            WatchdogRegistry::resume(pin_mut(&mut n), 5000);
+        }
+        assert!(!n.is_paused());
+        assert_eq!(n.last_touched_timestamp_ms, 5000);
+        assert!(!reg.check(5100));
+        assert!(reg.check(5101));
+    }
+
+    #[test]
+    fn test_reset_all_feeds_every_node() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
+        }
+
+        // Both nodes would be expired at t=1000 without the reset.
+        reg.reset_all(950);
+        assert_eq!(n1.last_touched_timestamp_ms, 950);
+        assert_eq!(n2.last_touched_timestamp_ms, 950);
+        assert!(!reg.check(1000));
+    }
+
+    #[test]
+    fn test_reset_all_keeps_latch() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(reg.check(150));
+
+        reg.reset_all(150);
+        assert!(reg.is_expired());
+        assert!(reg.check(160));
+    }
+
+    #[test]
+    fn test_assign_id() {
+        let mut n = WatchdogNode::default();
+        assert_eq!(n.id(), 0);
+
+        unsafe {
+            WatchdogRegistry::assign_id(pin_mut(&mut n), 42);
+        }
+        assert_eq!(n.id(), 42);
+    }
+
+    #[test]
+    fn test_timing_getters_after_add() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 250, 1234);
+        }
+        assert_eq!(n.timeout_interval_ms(), 250);
+        assert_eq!(n.last_touched_timestamp_ms(), 1234);
+
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 1300);
+        }
+        assert_eq!(n.timeout_interval_ms(), 250);
+        assert_eq!(n.last_touched_timestamp_ms(), 1300);
+    }
+
+    #[test]
+    fn test_time_to_expiry_freshly_fed() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 200, 1000);
+        }
+        assert_eq!(
+            WatchdogRegistry::time_to_expiry(unsafe { pin_ref(&n) }, 1000),
+            200
+        );
+    }
+
+    #[test]
+    fn test_time_to_expiry_near_expiry() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 200, 1000);
+        }
+        assert_eq!(
+            WatchdogRegistry::time_to_expiry(unsafe { pin_ref(&n) }, 1190),
+            10
+        );
+        // Exactly at the boundary: no time left, but `check` is still healthy.
+        assert_eq!(
+            WatchdogRegistry::time_to_expiry(unsafe { pin_ref(&n) }, 1200),
+            0
+        );
+        assert!(!reg.check(1200));
+    }
+
+    #[test]
+    fn test_time_to_expiry_past_timeout() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 200, 1000);
+        }
+        assert_eq!(
+            WatchdogRegistry::time_to_expiry(unsafe { pin_ref(&n) }, 1500),
+            0
+        );
+    }
+
+    #[test]
+    fn test_time_to_expiry_wrapping_time() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        // Feed near u32::MAX
+        unsafe {
+            reg.add(pin_mut(&mut n), 200, u32::MAX - 50);
+        }
+
+        // now = 100 → elapsed = 151 wrapping → 49 ms left, same as `check`.
+        assert_eq!(
+            WatchdogRegistry::time_to_expiry(unsafe { pin_ref(&n) }, 100),
+            49
+        );
+        // now = 300 → elapsed = 351 wrapping → expired.
+        assert_eq!(
+            WatchdogRegistry::time_to_expiry(unsafe { pin_ref(&n) }, 300),
+            0
+        );
+    }
+
+    #[test]
+    fn test_check_healthy() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 200, 0);
+        }
+
+        // 100 ms elapsed, timeout is 200 — still healthy
+        assert!(!reg.check(100));
+    }
+
+    #[test]
+    fn test_check_healthy_at_boundary() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 200, 0);
+        }
+
+        // Exactly at the timeout boundary — not expired (> required, not >=)
+        assert!(!reg.check(200));
+    }
+
+    #[test]
+    fn test_check_expired() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+
+        // 200 ms elapsed, timeout is 100 — expired
+        assert!(reg.check(200));
+    }
+
+    #[test]
+    fn test_check_expired_at_ms_set() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert_eq!(reg.expired_at_ms, 0);
+
+        assert!(reg.check(200));
+        assert_eq!(reg.expired_at_ms, 200);
+    }
+
+    #[test]
+    fn test_check_latching() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+
+        assert!(reg.check(200));
+        assert_eq!(reg.expired_at_ms, 200);
+
+        // Feed the node so it would be healthy again...
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 300);
+        }
+
+        // ...but the registry latches — still expired
+        assert!(reg.check(350));
+        // expired_at_ms should NOT change
+        assert_eq!(reg.expired_at_ms, 200);
+    }
+
+    #[test]
+    fn test_expired_at_none_before_expiry() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        assert_eq!(reg.expired_at(), None);
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(!reg.check(100));
+        assert_eq!(reg.expired_at(), None);
+    }
+
+    #[test]
+    fn test_expired_at_some_after_expiry() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(reg.check(101));
+        assert_eq!(reg.expired_at(), Some(101));
+
+        // Frozen at the first detection.
+        assert!(reg.check(500));
+        assert_eq!(reg.expired_at(), Some(101));
+
+        reg.clear_expired();
+        assert_eq!(reg.expired_at(), None);
+    }
+
+    #[test]
+    fn test_clear_expired_then_healthy() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(reg.check(200));
+
+        // Recover the task, then unlatch.
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 250);
+        }
+        reg.clear_expired();
+        assert!(!reg.is_expired());
+        assert_eq!(reg.expired_at_ms, 0);
+        // The list is left intact.
+        assert_eq!(count_nodes(reg.head), 1);
+
+        assert!(!reg.check(300));
+    }
+
+    #[test]
+    fn test_clear_expired_relatches() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(reg.check(200));
+
+        // The node is still stuck — clearing only defers the next latch.
+        reg.clear_expired();
+        assert!(reg.check(300));
+        assert!(reg.is_expired());
+        assert_eq!(reg.expired_at_ms, 300);
+    }
+
+    #[test]
+    fn test_on_expire_fires_once() {
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        static CALLS: AtomicU32 = AtomicU32::new(0);
+        static LAST_ID: AtomicU32 = AtomicU32::new(0);
+
+        fn on_expire(id: u32) {
+            CALLS.fetch_add(1, Ordering::Relaxed);
+            LAST_ID.store(id, Ordering::Relaxed);
+        }
+
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            WatchdogRegistry::assign_id(pin_mut(&mut n1), 1);
+            WatchdogRegistry::assign_id(pin_mut(&mut n2), 2);
+            reg.add(pin_mut(&mut n1), 500, 0); // healthy
+            reg.add(pin_mut(&mut n2), 100, 0); // expires
+        }
+        reg.set_on_expire(on_expire);
+
+        assert!(!reg.check(100));
+        assert_eq!(CALLS.load(Ordering::Relaxed), 0);
+
+        assert!(reg.check(200));
+        assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+        assert_eq!(LAST_ID.load(Ordering::Relaxed), 2);
+
+        // Latched: subsequent checks must not fire the hook again.
+        assert!(reg.check(300));
+        assert!(reg.check(1000));
+        assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_one_shot_removed_after_expiry() {
+        let mut reg = WatchdogRegistry::new();
+        let mut boot = WatchdogNode::default();
+        let mut task = WatchdogNode::default();
+
+        unsafe {
+            WatchdogRegistry::set_one_shot(pin_mut(&mut boot), true);
+            reg.add(pin_mut(&mut boot), 100, 0);
+            reg.add(pin_mut(&mut task), 500, 0);
+        }
+        assert!(boot.is_one_shot());
+
+        // The one-shot node expires: reported once, but no latch.
+        assert!(reg.check(150));
+        assert!(!reg.is_expired());
+        assert!(!reg.contains(unsafe { pin_ref(&boot) }));
+        assert!(boot.next.is_null());
+        assert_eq!(count_nodes(reg.head), 1);
+        assert_eq!(reg.tail, &mut task as *mut WatchdogNode);
+
+        // Subsequent scans do not see the removed node.
+        assert!(!reg.check(200));
+        assert!(!reg.is_expired());
+    }
+
+    #[test]
+    fn test_one_shot_does_not_mask_regular_expiry() {
+        let mut reg = WatchdogRegistry::new();
+        let mut boot = WatchdogNode::builder().id(1).one_shot(true).build();
+        let mut task = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut boot), 100, 0);
+            reg.add(pin_mut(&mut task), 100, 0);
+        }
+
+        // Both expire; the one-shot node is dropped and the regular one
+        // latches the registry.
+        assert!(reg.check(150));
+        assert!(reg.is_expired());
+        assert_eq!(count_nodes(reg.head), 1);
+
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(2));
+        assert_eq!(reg.next_expired(&mut cursor), None);
+    }
+
+    #[test]
+    fn test_check_all_one_shot_counts_without_latch() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().one_shot(true).build();
+        let mut n2 = WatchdogNode::builder().one_shot(true).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+        }
+
+        assert_eq!(reg.check_all(150), 2);
+        assert!(!reg.is_expired());
+        assert!(reg.head.is_null());
+        assert!(reg.tail.is_null());
+        assert_eq!(reg.check_all(300), 0);
+    }
+
+    #[test]
+    fn test_check_force_detects_later_expiry() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 300, 0);
+        }
+        assert!(reg.check(150));
+
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+        assert_eq!(reg.next_expired(&mut cursor), None);
+
+        // n2 expires after the latch; `check` would not look again.
+        assert!(reg.check_force(400));
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+        assert_eq!(reg.next_expired(&mut cursor), Some(2));
+        assert_eq!(reg.next_expired(&mut cursor), None);
+
+        // The first detection time is preserved.
+        assert_eq!(reg.expired_at(), Some(150));
+    }
+
+    #[test]
+    fn test_check_force_latches_when_healthy_before() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(!reg.check_force(100));
+        assert!(!reg.is_expired());
+
+        assert!(reg.check_force(101));
+        assert_eq!(reg.expired_at(), Some(101));
+
+        // Re-fed after latching: the scan is clean, the latch stays.
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 200);
+        }
+        assert!(!reg.check_force(250));
+        assert!(reg.is_expired());
+    }
+
+    /// Helper: register three nodes (ids 1..=3) with different margins and
+    /// take a snapshot at t=150 into a buffer of `N` records.
+    fn snapshot_three<const N: usize>() -> ([NodeStatus<u32>; N], usize) {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
+            reg.add(pin_mut(&mut n3), 300, 100);
+        }
+
+        let mut out = [NodeStatus::default(); N];
+        let written = reg.snapshot(150, &mut out);
+        assert!(!reg.is_expired(), "snapshot must not latch");
+        (out, written)
+    }
+
+    const STATUS_1: NodeStatus<u32> = NodeStatus {
+        id: 1,
+        timeout_ms: 100,
+        remaining_ms: 0,
+        expired: true,
+    };
+    const STATUS_2: NodeStatus<u32> = NodeStatus {
+        id: 2,
+        timeout_ms: 200,
+        remaining_ms: 50,
+        expired: false,
+    };
+    const STATUS_3: NodeStatus<u32> = NodeStatus {
+        id: 3,
+        timeout_ms: 300,
+        remaining_ms: 250,
+        expired: false,
+    };
+
+    #[test]
+    fn test_snapshot_buffer_smaller() {
+        let (out, written) = snapshot_three::<2>();
+
+        assert_eq!(written, 2);
+        assert_eq!(out, [STATUS_1, STATUS_2]);
+    }
+
+    #[test]
+    fn test_snapshot_buffer_equal() {
+        let (out, written) = snapshot_three::<3>();
+
+        assert_eq!(written, 3);
+        assert_eq!(out, [STATUS_1, STATUS_2, STATUS_3]);
+    }
+
+    #[test]
+    fn test_snapshot_buffer_larger() {
+        let (out, written) = snapshot_three::<5>();
+
+        assert_eq!(written, 3);
+        assert_eq!(out[..written], [STATUS_1, STATUS_2, STATUS_3]);
+        assert_eq!(out[written..], [NodeStatus::default(); 2]);
+    }
+
+    #[test]
+    fn test_snapshot_remaining_matches_expired() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(1).build();
+
+        reg.set_grace_ms(50);
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        let mut out = [NodeStatus::default(); 1];
+
+        // Past the timeout but within the grace period.
+        reg.snapshot(120, &mut out);
+        assert_eq!((out[0].expired, out[0].remaining_ms), (false, 30));
+        reg.snapshot(151, &mut out);
+        assert_eq!((out[0].expired, out[0].remaining_ms), (true, 0));
+
+        // An inclusive registry expires exactly at the boundary.
+        let mut reg = WatchdogRegistry::new_inclusive();
+        let mut n = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        reg.snapshot(99, &mut out);
+        assert_eq!((out[0].expired, out[0].remaining_ms), (false, 1));
+        reg.snapshot(100, &mut out);
+        assert_eq!((out[0].expired, out[0].remaining_ms), (true, 0));
+    }
+
+    #[test]
+    fn test_worst_margin_empty() {
+        let reg = WatchdogRegistry::new();
+
+        assert_eq!(reg.worst_margin(100), None);
+    }
+
+    #[test]
+    fn test_worst_margin_reports_tightest() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 500, 0); // 400 ms left at t=100
+            reg.add(pin_mut(&mut n2), 130, 0); // 30 ms left at t=100
+            reg.add(pin_mut(&mut n3), 100, 50); // 50 ms left at t=100
+        }
+
+        assert_eq!(reg.worst_margin(100), Some((2, 30)));
+    }
+
+    #[test]
+    fn test_worst_margin_expired_wins() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 200);
+            reg.add(pin_mut(&mut n2), 100, 0);
+        }
+
+        assert_eq!(reg.worst_margin(250), Some((2, 0)));
+    }
+
+    #[test]
+    fn test_worst_margin_wrapping() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(7).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, u32::MAX - 10);
+        }
+
+        // 40 ms elapsed across the wrap.
+        assert_eq!(reg.worst_margin(29), Some((7, 60)));
+    }
+
+    #[test]
+    fn test_min_margin_tracks_worst_scan() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert_eq!(n.min_margin_ms(), u32::MAX);
+
+        // Fed every 40 ms, scanned right before each feed.
+        reg.check_all(40);
+        assert_eq!(n.min_margin_ms(), 60);
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 40);
+        }
+
+        // One slow iteration: 90 ms between feeds.
+        reg.check_all(130);
+        assert_eq!(n.min_margin_ms(), 10);
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 130);
+        }
+
+        // Faster feeds do not raise the mark again.
+        reg.check_all(150);
+        assert_eq!(n.min_margin_ms(), 10);
+        assert!(!reg.is_expired());
+    }
+
+    #[test]
+    fn test_min_margin_not_updated_by_check() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(!reg.check(90));
+        assert_eq!(n.min_margin_ms(), u32::MAX);
+    }
+
+    #[test]
+    fn test_min_margin_zero_when_expired_and_reset_on_add() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert_eq!(reg.check_all(150), 1);
+        assert_eq!(n.min_margin_ms(), 0);
+
+        // Re-registering after removal starts a fresh measurement.
+        unsafe {
+            reg.remove(pin_mut(&mut n));
+            n.min_margin_ms = 0; // as if zero-initialized from C
+            reg.add(pin_mut(&mut n), 100, 200);
+        }
+        assert_eq!(n.min_margin_ms(), u32::MAX);
+    }
+
+    #[test]
+    fn test_exclusive_boundary_default() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 1);
+        }
+        // Elapsed exactly equal to the timeout is still healthy.
+        assert!(!reg.check(100));
+        assert!(reg.check(101));
+
+        // At the snapshot n1 is past its deadline, n2 exactly at it.
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+        assert_eq!(reg.next_expired(&mut cursor), None);
+    }
+
+    #[test]
+    fn test_inclusive_boundary() {
+        let mut reg = WatchdogRegistry::new_inclusive();
+        let mut n = WatchdogNode::builder().id(1).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(!reg.check(99));
+        // Fed exactly at the deadline counts as late.
+        assert!(reg.check(100));
+        assert_eq!(reg.expired_at(), Some(100));
+
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+        assert_eq!(reg.next_expired(&mut cursor), None);
+    }
+
+    #[test]
+    fn test_inclusive_boundary_survives_init() {
+        let mut reg = WatchdogRegistry::new_inclusive();
+        let mut n = WatchdogNode::default();
+
+        reg.init();
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert_eq!(reg.check_all(100), 1);
+    }
+
+    #[test]
+    fn test_grace_period_extends_timeouts() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(1).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+
+        reg.set_grace_ms(50);
+        // Would be expired without grace.
+        assert!(!reg.check(150));
+
+        // Dropping the grace makes the node expire again.
+        reg.set_grace_ms(0);
+        assert!(reg.check(150));
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+    }
+
+    #[test]
+    fn test_grace_period_saturates() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        reg.set_grace_ms(u32::MAX);
+        assert!(!reg.check(u32::MAX / 2));
+
+        reg.init();
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(reg.check(101), "init must clear the grace period");
+    }
+
+    #[test]
+    fn test_max_timeout_never_expires() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(1).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), u32::MAX, 1_000);
+        }
+        reg.set_grace_ms(5_000);
+
+        // Up to just under 49.7 days of elapsed time, wrapping included.
+        for now in [1_001, 6_000, u32::MAX / 2, u32::MAX, 0, 998] {
+            assert!(!reg.check(now), "now = {now}");
+            assert_eq!(reg.check_all(now), 0, "now = {now}");
+        }
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), None);
+    }
+
+    #[test]
+    fn test_max_timeout_saturating_registry() {
+        let mut reg = WatchdogRegistry::new_saturating();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), u32::MAX, 0);
+        }
+        reg.set_grace_ms(u32::MAX);
+
+        assert!(!reg.check(u32::MAX));
+    }
+
+    #[test]
+    fn test_health_states() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().warn_interval_ms(50).build();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
+        }
+
+        assert_eq!(reg.health(50), Health::Ok);
+        assert_eq!(reg.health(60), Health::Warning);
+        assert_eq!(reg.health(101), Health::Expired);
+
+        // Read-only: nothing latched.
+        assert!(!reg.is_expired());
+    }
+
+    #[test]
+    fn test_health_expired_outranks_warning() {
+        let mut reg = WatchdogRegistry::new();
+        let mut warn = WatchdogNode::builder().warn_interval_ms(50).build();
+        let mut dead = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut warn), 1_000, 0);
+            reg.add(pin_mut(&mut dead), 100, 0);
+        }
+
+        assert_eq!(reg.health(150), Health::Expired);
+        assert!(Health::Ok < Health::Warning && Health::Warning < Health::Expired);
+    }
+
+    #[test]
+    fn test_next_expired_cursor_node_removed() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            reg.add(pin_mut(&mut n3), 100, 0);
+        }
+        assert!(reg.check(150));
+
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+        assert_eq!(reg.next_expired(&mut cursor), Some(2));
+
+        // Remove the cursor's node: iteration restarts from the head.
+        unsafe {
+            reg.remove(pin_mut(&mut n2));
+        }
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+        assert_eq!(reg.next_expired(&mut cursor), Some(3));
+        assert_eq!(reg.next_expired(&mut cursor), None);
+    }
+
+    #[test]
+    fn test_cursor_iterates_expired_nodes() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 500, 0);
+            reg.add(pin_mut(&mut n3), 100, 0);
+        }
+        assert!(reg.check(150));
+
+        let mut cursor = Cursor::start();
+        let mut ids = [0; 3];
+        let mut count = 0;
+        while let Some(id) = reg.next_expired(&mut cursor) {
+            ids[count] = id;
+            count += 1;
+        }
+        assert_eq!(&ids[..count], &[1, 3]);
+
+        // An exhausted cursor stays exhausted until it is reset.
+        assert_eq!(reg.next_expired(&mut cursor), None);
+        cursor.reset();
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+        assert_eq!(reg.next_expired(&mut Cursor::default()), Some(1));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_cursor_matches_raw_iteration() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+        }
+        assert!(reg.check(150));
+
+        let mut cursor = Cursor::start();
+        let mut raw: *const WatchdogNode = ptr::null();
+        loop {
+            let expected = reg.next_expired_raw(&mut raw);
+            assert_eq!(reg.next_expired(&mut cursor), expected);
+            if expected.is_none() {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_reset_cursor_restarts_iteration() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(4).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(reg.check(150));
+
+        let mut cursor: *const WatchdogNode = ptr::null();
+        assert_eq!(reg.next_expired_raw(&mut cursor), Some(4));
+        assert_eq!(reg.next_expired_raw(&mut cursor), None);
+
+        WatchdogRegistry::reset_cursor(&mut cursor);
+        assert!(cursor.is_null());
+        assert_eq!(reg.next_expired_raw(&mut cursor), Some(4));
+    }
+
+    #[test]
+    fn test_scan_reports_every_node_and_latches() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 300, 0);
+            reg.add(pin_mut(&mut n3), 100, 0);
+        }
+
+        let mut seen = [(0, false); 3];
+        let mut count = 0;
+        reg.scan(50, |id, expired| {
+            seen[count] = (id, expired);
+            count += 1;
+        });
+        assert_eq!(seen, [(1, false), (2, false), (3, false)]);
+        assert!(!reg.is_expired());
+
+        count = 0;
+        reg.scan(150, |id, expired| {
+            seen[count] = (id, expired);
+            count += 1;
+        });
+        assert_eq!(count, 3);
+        assert_eq!(seen, [(1, true), (2, false), (3, true)]);
+        assert!(reg.is_expired());
+        assert_eq!(reg.expired_at(), Some(150));
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn test_log_records_expiry() {
+        use std::string::{String, ToString};
+        use std::sync::Mutex;
+        use std::vec::Vec;
+
+        struct Capture(Mutex<Vec<(log::Level, String)>>);
+
+        impl log::Log for Capture {
+            fn enabled(&self, _: &log::Metadata<'_>) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record<'_>) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((record.level(), record.args().to_string()));
+            }
+
+            fn flush(&self) {}
+        }
+
+        static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+        log::set_logger(&CAPTURE).unwrap();
+        log::set_max_level(log::LevelFilter::Debug);
+
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(42).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(reg.check(4_321));
+        reg.clear_expired();
+        unsafe {
+            reg.remove(pin_mut(&mut n));
+        }
+
+        let records = CAPTURE.0.lock().unwrap();
+        for expected in [
+            (log::Level::Debug, "watchdog 42 registered with timeout 100"),
+            (log::Level::Warn, "watchdog 42 expired at 4321"),
+            (log::Level::Debug, "watchdog latch cleared"),
+            (log::Level::Debug, "watchdog 42 unregistered"),
+        ] {
+            assert!(
+                records
+                    .iter()
+                    .any(|(level, msg)| *level == expected.0 && msg == expected.1),
+                "missing {expected:?}"
+            );
+        }
+    }
+
+    #[cfg(feature = "names")]
+    #[test]
+    fn test_name_defaults_to_none() {
+        let n = WatchdogNode::default();
+        assert_eq!(n.name(), None);
+        assert_eq!(WatchdogNode::builder().build().name(), None);
+    }
+
+    #[cfg(feature = "names")]
+    #[test]
+    fn test_name_survives_add_and_feed() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(5).build();
+
+        unsafe {
+            WatchdogRegistry::set_name(pin_mut(&mut n), "sensor_task");
+            reg.add(pin_mut(&mut n), 100, 0);
+            WatchdogRegistry::feed(pin_mut(&mut n), 50);
+        }
+        assert_eq!(n.name(), Some("sensor_task"));
+
+        assert!(reg.check(200));
+        let mut cursor = Cursor::start();
+        let event = reg.next_expiry_event(&mut cursor).unwrap();
+        assert_eq!(event.name, Some("sensor_task"));
+    }
+
+    #[test]
+    fn test_feed_all_with_ids() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            reg.add(pin_mut(&mut n3), 100, 0);
+        }
+
+        // All match.
+        assert_eq!(reg.feed_all_with_ids(&[1, 2, 3], 50), 3);
+        assert_eq!(n3.last_touched_timestamp_ms(), 50);
+
+        // Partial match: 9 is unknown, node 2 is left alone.
+        assert_eq!(reg.feed_all_with_ids(&[3, 9, 1], 120), 2);
+        assert_eq!(n1.last_touched_timestamp_ms(), 120);
+        assert_eq!(n2.last_touched_timestamp_ms(), 50);
+        assert_eq!(n3.last_touched_timestamp_ms(), 120);
+
+        // Only unknown ids.
+        assert_eq!(reg.feed_all_with_ids(&[7, 8], 200), 0);
+        assert_eq!(reg.feed_all_with_ids(&[], 200), 0);
+        assert_eq!(n1.last_touched_timestamp_ms(), 120);
+    }
+
+    #[test]
+    fn test_first_expired_id() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 500, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+        }
+        assert_eq!(reg.first_expired_id(), None);
+
+        assert!(!reg.check(100));
+        assert_eq!(reg.first_expired_id(), None);
+
+        assert!(reg.check(150));
+        assert_eq!(reg.first_expired_id(), Some(2));
+
+        // Frozen while latched, even once another node expires.
+        assert!(reg.check(600));
+        assert_eq!(reg.first_expired_id(), Some(2));
+
+        reg.clear_expired();
+        assert_eq!(reg.first_expired_id(), None);
+    }
+
+    #[test]
+    fn test_verify_integrity_healthy_list() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+        let mut n3 = WatchdogNode::default();
+
+        assert!(reg.verify_integrity(0));
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            reg.add(pin_mut(&mut n3), 100, 0);
+        }
+
+        assert!(reg.verify_integrity(3));
+        // More nodes than expected counts as an overrun.
+        assert!(!reg.verify_integrity(2));
+    }
+
+    #[test]
+    fn test_verify_integrity_detects_cycle() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+        let mut n3 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            reg.add(pin_mut(&mut n3), 100, 0);
+        }
+
+        // Corrupt the list through the registry's own pointers: the tail
+        // points back to the second node.
+        unsafe {
+            (*reg.tail).next = &raw mut n2;
+        }
+        assert!(!reg.verify_integrity(usize::MAX));
+        assert!(!reg.verify_integrity(10));
+
+        // A self-loop on the head.
+        unsafe {
+            (*reg.head).next = reg.head;
+        }
+        assert!(!reg.verify_integrity(usize::MAX));
+    }
+
+    #[test]
+    fn test_replace_head() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut new = WatchdogNode::builder().id(10).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            assert!(reg.replace(pin_mut(&mut n1), pin_mut(&mut new), 300, 50));
+        }
+
+        assert!(reg.iter_ids().eq([10, 2]));
+        assert!(n1.next.is_null());
+        assert!(!n1.is_linked());
+        assert!(new.is_linked());
+        assert_eq!(new.timeout_interval_ms(), 300);
+        assert_eq!(new.last_touched_timestamp_ms(), 50);
+        assert!(reg.verify_integrity(2));
+    }
+
+    #[test]
+    fn test_replace_middle_and_tail() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+        let mut new2 = WatchdogNode::builder().id(20).build();
+        let mut new3 = WatchdogNode::builder().id(30).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            reg.add(pin_mut(&mut n3), 100, 0);
+            assert!(reg.replace(pin_mut(&mut n2), pin_mut(&mut new2), 100, 0));
+            assert!(reg.replace(pin_mut(&mut n3), pin_mut(&mut new3), 100, 0));
+        }
+
+        assert!(reg.iter_ids().eq([1, 20, 30]));
+        assert!(n2.next.is_null());
+        // The tail moved to the replacement, so appending still works.
+        let mut n4 = WatchdogNode::builder().id(4).build();
+        unsafe {
+            reg.add(pin_mut(&mut n4), 100, 0);
+        }
+        assert!(reg.iter_ids().eq([1, 20, 30, 4]));
+        assert!(reg.verify_integrity(4));
+    }
+
+    #[test]
+    fn test_replace_missing_old() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut missing = WatchdogNode::builder().id(2).build();
+        let mut new = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            assert!(!reg.replace(pin_mut(&mut missing), pin_mut(&mut new), 100, 0));
+        }
+
+        assert!(reg.iter_ids().eq([1]));
+        assert!(!new.is_linked());
+    }
+
+    #[test]
+    fn test_avg_feed_gap_fixed_cadence() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert_eq!(n.avg_feed_gap_ms(), None);
+
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 40);
+        }
+        assert_eq!(n.avg_feed_gap_ms(), None, "one feed has no gap");
+
+        for now in [80, 120, 160, 200] {
+            unsafe {
+                WatchdogRegistry::feed(pin_mut(&mut n), now);
+            }
+        }
+        assert_eq!(n.avg_feed_gap_ms(), Some(40));
+
+        // Re-adding after removal restarts the statistics.
+        unsafe {
+            reg.remove(pin_mut(&mut n));
+            reg.add(pin_mut(&mut n), 100, 300);
+        }
+        assert_eq!(n.avg_feed_gap_ms(), None);
+    }
+
+    #[test]
+    fn test_avg_feed_gap_across_wrap() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, u32::MAX - 100);
+            WatchdogRegistry::feed(pin_mut(&mut n), u32::MAX - 60);
+            WatchdogRegistry::feed(pin_mut(&mut n), u32::MAX - 20);
+            // Wraps: (u32::MAX - 20) + 40 = 19.
+            WatchdogRegistry::feed(pin_mut(&mut n), 19);
+        }
+        assert_eq!(n.avg_feed_gap_ms(), Some(40));
+    }
+
+    #[test]
+    fn test_tick_scale_matches_ms_registry() {
+        let mut ms = WatchdogRegistry::new();
+        let mut ticks = WatchdogRegistry::new_with_tick(10);
+        let mut a = WatchdogNode::builder().id(1).build();
+        let mut b = WatchdogNode::builder().id(1).build();
+
+        // Same 250 ms timeout; the tick registry is fed at tick 3 = 30 ms.
+        unsafe {
+            ms.add(pin_mut(&mut a), 250, 30);
+            ticks.add(pin_mut(&mut b), 250, 3);
+        }
+
+        for tick in [10, 20, 28, 29] {
+            let now_ms = tick * 10;
+            assert_eq!(ms.check(now_ms), ticks.check(tick), "tick = {tick}");
+        }
+        assert!(ticks.is_expired());
+        assert_eq!(ticks.expired_at(), Some(29));
+
+        let mut cursor = Cursor::start();
+        assert_eq!(ticks.next_expired(&mut cursor), Some(1));
+
+        let mut out = [NodeStatus::default(); 1];
+        ticks.clear_expired();
+        assert_eq!(ticks.snapshot(20, &mut out), 1);
+        assert_eq!(out[0].remaining_ms, 80);
+    }
+
+    #[test]
+    fn test_tick_scale_saturates() {
+        let mut reg = WatchdogRegistry::new_with_tick(1_000);
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), u32::MAX, 0);
+        }
+        // 5_000_000 ticks * 1_000 ms would overflow `u32`; it saturates at
+        // `u32::MAX`, which is not past the timeout.
+        assert!(!reg.check(5_000_000));
+    }
+
+    #[test]
+    fn test_tick_scale_zero_is_one() {
+        let mut reg = WatchdogRegistry::new_with_tick(0);
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(!reg.check(100));
+        assert!(reg.check(101));
+    }
+
+    #[test]
+    fn test_add_reporting() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        assert!(unsafe { reg.add_reporting(pin_mut(&mut n), 100, 0) });
+        assert_eq!(count_nodes(reg.head), 1);
+        assert!(n.is_linked());
+
+        // Second call updates timeout and timestamp without inserting.
+        assert!(!unsafe { reg.add_reporting(pin_mut(&mut n), 250, 40) });
+        assert_eq!(count_nodes(reg.head), 1);
+        assert_eq!(n.timeout_interval_ms(), 250);
+        assert_eq!(n.last_touched_timestamp_ms(), 40);
+    }
+
+    #[test]
+    fn test_time_anomaly_on_backward_jump() {
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        static ANOMALIES: AtomicU32 = AtomicU32::new(0);
+        static PREV: AtomicU32 = AtomicU32::new(0);
+        fn hook(prev: u32, now: u32) {
+            ANOMALIES.fetch_add(1, Ordering::Relaxed);
+            PREV.store(prev, Ordering::Relaxed);
+            assert_eq!(now, 500);
+        }
+
+        let mut reg = WatchdogRegistry::new();
+        reg.set_on_time_anomaly(hook);
+
+        assert!(!reg.check(1_000));
+        assert!(!reg.check(2_000));
+        assert!(!reg.check(u32::MAX / 2));
+        assert_eq!(ANOMALIES.load(Ordering::Relaxed), 0);
+
+        // Jump backwards: the wrapped delta exceeds half the range.
+        assert!(!reg.check(500));
+        assert_eq!(ANOMALIES.load(Ordering::Relaxed), 1);
+        assert_eq!(PREV.load(Ordering::Relaxed), u32::MAX / 2);
+
+        // Regular wrap-around is not an anomaly.
+        assert!(!reg.check(u32::MAX / 2));
+        assert!(!reg.check(u32::MAX - 10));
+        assert!(!reg.check(20));
+        assert_eq!(ANOMALIES.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_expired_ids() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 500, 0);
+            reg.add(pin_mut(&mut n3), 100, 0);
+        }
+
+        let mut out = [0u32; 4];
+        // Not latched yet.
+        assert_eq!(reg.expired_ids(&mut out), 0);
+
+        assert!(reg.check(150));
+        assert_eq!(reg.expired_ids(&mut out), 2);
+        assert_eq!(out[..2], [1, 3]);
+
+        // Buffer smaller than the expired count.
+        let mut small = [0u32; 1];
+        assert_eq!(reg.expired_ids(&mut small), 1);
+        assert_eq!(small, [1]);
+
+        assert_eq!(reg.expired_ids(&mut []), 0);
+    }
+
+    #[test]
+    fn test_deadline_met() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(1).build();
+
+        unsafe {
+            reg.add_deadline(pin_mut(&mut n), 1_000, 200);
+        }
+        assert!(n.is_deadline());
+        assert!(!reg.check(900));
+        unsafe {
+            reg.complete(pin_mut(&mut n));
+        }
+        assert!(!n.is_linked());
+        assert!(!reg.check(5_000));
+    }
+
+    #[test]
+    fn test_deadline_missed_despite_feeds() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(7).build();
+
+        unsafe {
+            reg.add_deadline(pin_mut(&mut n), 1_000, 200);
+            // Feeding does not extend a deadline.
+            WatchdogRegistry::feed(pin_mut(&mut n), 900);
+        }
+        assert!(!reg.check(1_000));
+        assert!(reg.check(1_001));
+        assert_eq!(reg.first_expired_id(), Some(7));
+
+        // A regular `add` turns it back into an interval watchdog.
+        reg.clear_expired();
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 1_100);
+        }
+        assert!(!n.is_deadline());
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 1_150);
+        }
+        assert!(!reg.check(1_250));
+    }
+
+    #[test]
+    fn test_deadline_survives_reset_all_and_resume() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add_deadline(pin_mut(&mut n), 1_000, 200);
+        }
+        reg.reset_all(900);
+        assert!(!reg.check(1_000));
+        assert!(reg.check(1_001));
+
+        reg.clear_expired();
+        unsafe {
+            WatchdogRegistry::pause(pin_mut(&mut n));
+            WatchdogRegistry::resume(pin_mut(&mut n), 950);
+        }
+        assert!(reg.check(1_001));
+    }
+
+    #[test]
+    fn test_deadline_rejected_at_capacity() {
+        let mut reg = WatchdogRegistry::with_capacity(1);
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add_deadline(pin_mut(&mut n2), 1_000, 0);
+        }
+        assert!(!n2.is_linked());
+        assert!(!n2.is_deadline());
+    }
+
+    #[test]
+    fn test_deadline_in_the_past() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add_deadline(pin_mut(&mut n), 100, 500);
+        }
+        assert!(reg.check(500));
+    }
+
+    #[test]
+    fn test_deadline_wraps() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add_deadline(pin_mut(&mut n), 49, u32::MAX - 50);
+        }
+        assert!(!reg.check(u32::MAX));
+        assert!(!reg.check(49));
+        assert!(reg.check(50));
+    }
+
+    #[test]
+    fn test_same_as_compares_identity() {
+        let a = WatchdogNode::default();
+        let b = WatchdogNode::default();
+
+        assert!(a.same_as(&a));
+        assert!(!a.same_as(&b));
+        assert!(!b.same_as(&a));
+    }
+
+    #[test]
+    fn test_with_capacity_rejects_extra_nodes() {
+        let mut reg = WatchdogRegistry::with_capacity(2);
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+        let mut n3 = WatchdogNode::default();
+
+        unsafe {
+            assert_eq!(reg.try_add(pin_mut(&mut n1), 100, 0), Ok(()));
+            assert!(reg.add_reporting(pin_mut(&mut n2), 100, 0));
+            assert_eq!(
+                reg.try_add(pin_mut(&mut n3), 100, 0),
+                Err(WatchdogError::Full)
+            );
+            // Re-adding a registered node is not limited by the cap.
+            assert!(!reg.add_reporting(pin_mut(&mut n2), 200, 10));
+            assert_eq!(n2.timeout_interval_ms(), 200);
+
+            for _ in 0..4 {
+                reg.add(pin_mut(&mut n3), 100, 0);
+            }
+        }
+        assert!(!n3.is_linked());
+        assert_eq!(count_nodes(reg.head), 2);
+
+        unsafe {
+            reg.remove(pin_mut(&mut n1));
+            assert!(reg.add_reporting(pin_mut(&mut n3), 100, 0));
+        }
+        assert_eq!(count_nodes(reg.head), 2);
+    }
+
+    #[test]
+    fn test_merge_respects_capacity() {
+        let mut reg = WatchdogRegistry::with_capacity(2);
+        let mut other = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            other.add(pin_mut(&mut n2), 100, 0);
+            other.add(pin_mut(&mut n3), 100, 0);
+        }
+        assert_eq!(reg.merge(&mut other), Err(WatchdogError::Full));
+        assert!(reg.iter_ids().eq([1]));
+        assert!(other.iter_ids().eq([2, 3]));
+
+        unsafe {
+            other.remove(pin_mut(&mut n3));
+        }
+        assert_eq!(reg.merge(&mut other), Ok(()));
+        assert!(reg.iter_ids().eq([1, 2]));
+        assert!(reg.verify_integrity(2));
+        assert!(other.verify_integrity(0));
+
+        // The merged node counts against the cap.
+        unsafe {
+            assert_eq!(
+                reg.try_add(pin_mut(&mut n3), 100, 0),
+                Err(WatchdogError::Full)
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_capacity_zero() {
+        let mut reg = WatchdogRegistry::with_capacity(0);
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            assert!(!reg.add_reporting(pin_mut(&mut n), 100, 0));
+        }
+        assert!(reg.head.is_null());
+    }
+
+    #[test]
+    fn test_checkpoint_restore_round_trips_timestamps() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 50);
+        }
+
+        let mut saved = [0u32; 4];
+        let cp = reg.checkpoint(80, &mut saved);
+        assert_eq!(cp.nodes(), 2);
+        assert_eq!(saved[..2], [0, 50]);
+
+        // Uninstrumented phase: nobody feeds for a long time.
+        reg.restore(&cp, &saved, 10_080);
+        assert_eq!(n1.last_touched_timestamp_ms(), 10_000);
+        assert_eq!(n2.last_touched_timestamp_ms(), 10_050);
+        assert!(!reg.check(10_100));
+        assert!(reg.check(10_101));
+    }
+
+    #[test]
+    fn test_checkpoint_restore_round_trips_latch() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(reg.check(150));
+
+        let cp = reg.checkpoint(200, &mut []);
+        assert_eq!(cp.nodes(), 0);
+        reg.clear_expired();
+
+        reg.restore(&cp, &[], 300);
+        assert!(reg.is_expired());
+        assert_eq!(reg.expired_at(), Some(250));
+        assert_eq!(reg.first_expired_id(), Some(3));
+        // Uncaptured node timestamps are left alone.
+        assert_eq!(n.last_touched_timestamp_ms(), 0);
+    }
+
+    #[test]
+    fn test_try_add_rejects_zero_timeout() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            assert_eq!(
+                reg.try_add(pin_mut(&mut n), 0, 0),
+                Err(WatchdogError::ZeroTimeout)
+            );
+            assert!(!n.is_linked());
+            assert_eq!(reg.try_add(pin_mut(&mut n), 1, 0), Ok(()));
+        }
+        assert!(n.is_linked());
+        assert!(!reg.check(1));
+        assert!(reg.check(2));
+    }
+
+    #[test]
+    fn test_expiry_count_accumulates_across_clear() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 500, 0);
+        }
+        assert_eq!(n1.expiry_count(), 0);
+
+        assert_eq!(reg.check_all(150), 1);
+        assert_eq!(n1.expiry_count(), 1);
+
+        // Recover, clear and expire again.
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n1), 160);
+        }
+        reg.clear_expired();
+        assert_eq!(reg.check_all(200), 0);
+        assert_eq!(n1.expiry_count(), 1);
+        assert_eq!(reg.check_all(300), 1);
+        assert_eq!(n1.expiry_count(), 2);
+        assert_eq!(n2.expiry_count(), 0);
+    }
+
+    #[test]
+    fn test_register_from_specs() {
+        let mut reg = WatchdogRegistry::new();
+        let mut nodes = core::pin::pin!(core::array::from_fn::<_, 4, _>(|_| {
+            WatchdogNode::default()
+        }));
+
+        let specs = [(1, 100), (2, 200), (3, 300)];
+        assert_eq!(reg.register_from(nodes.as_mut(), &specs, 10), 3);
+
+        let registered: std::vec::Vec<_> = reg
+            .iter()
+            .map(|node| (node.id(), node.timeout_interval_ms()))
+            .collect();
+        assert_eq!(registered, specs);
+        assert!(!nodes[3].is_linked());
+        assert_eq!(nodes[0].last_touched_timestamp_ms(), 10);
+    }
+
+    #[test]
+    fn test_time_since_expiry() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(!reg.check(100));
+        assert_eq!(reg.time_since_expiry(100), None);
+
+        assert!(reg.check(150));
+        assert_eq!(reg.time_since_expiry(150), Some(0));
+        assert_eq!(reg.time_since_expiry(1_150), Some(1_000));
+
+        reg.clear_expired();
+        assert_eq!(reg.time_since_expiry(1_150), None);
+    }
+
+    #[test]
+    fn test_time_since_expiry_wraps() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, u32::MAX - 200);
+        }
+        assert!(reg.check(u32::MAX - 50));
+        assert_eq!(reg.time_since_expiry(49), Some(100));
+    }
+
+    #[test]
+    fn test_check_group_scans_only_its_group() {
+        const COMMS: u8 = 1;
+        const SENSORS: u8 = 2;
+
+        let mut reg = WatchdogRegistry::new();
+        let mut radio = WatchdogNode::builder().id(1).build();
+        let mut imu = WatchdogNode::builder().id(2).build();
+        let mut baro = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            WatchdogRegistry::set_group(pin_mut(&mut radio), COMMS);
+            WatchdogRegistry::set_group(pin_mut(&mut imu), SENSORS);
+            WatchdogRegistry::set_group(pin_mut(&mut baro), SENSORS);
+            reg.add(pin_mut(&mut radio), 500, 0);
+            reg.add(pin_mut(&mut imu), 100, 0);
+            reg.add(pin_mut(&mut baro), 100, 0);
+            WatchdogRegistry::feed(pin_mut(&mut baro), 100);
+        }
+        assert_eq!(imu.group(), SENSORS);
+
+        assert!(!reg.check_group(COMMS, 150));
+        assert!(!reg.is_expired());
+
+        assert!(reg.check_group(SENSORS, 150));
+        assert_eq!(reg.first_expired_id(), Some(2));
+
+        // The latch is global, but the healthy group still reads healthy.
+        assert!(reg.check(160));
+        assert!(!reg.check_group(COMMS, 160));
+        assert!(!reg.check_group(0, 160));
+    }
+
+    #[test]
+    fn test_next_warning() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).warn_interval_ms(50).build();
+        let mut n2 = WatchdogNode::builder().id(2).warn_interval_ms(50).build();
+        let mut n3 = WatchdogNode::builder().id(3).warn_interval_ms(50).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            reg.add(pin_mut(&mut n3), 300, 0);
+            WatchdogRegistry::feed(pin_mut(&mut n2), 90);
+        }
+
+        // n1 in its band, n2 fed recently, n3 in its band.
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_warning(&mut cursor, 80), Some(1));
+        assert_eq!(reg.next_warning(&mut cursor, 80), Some(3));
+        assert_eq!(reg.next_warning(&mut cursor, 80), None);
+
+        // Expired nodes are not warnings.
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_warning(&mut cursor, 150), Some(2));
+        assert_eq!(reg.next_warning(&mut cursor, 150), Some(3));
+        assert_eq!(reg.next_warning(&mut cursor, 150), None);
+        assert!(!reg.is_expired());
+    }
+
+    #[test]
+    fn test_non_latching_recovers_after_feed() {
+        let mut reg = WatchdogRegistry::new_non_latching();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            WatchdogRegistry::feed(pin_mut(&mut n1), 100);
+        }
+        assert!(reg.check(150));
+        assert_eq!(reg.expired_at(), Some(150));
+
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n2), 160);
+        }
+        assert!(!reg.check(170));
+        assert!(!reg.is_expired());
+
+        // Expired again: `next_expired` uses the latest `check` time.
+        assert!(reg.check(230));
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+        assert_eq!(reg.next_expired(&mut cursor), None);
+        assert_eq!(reg.expired_at(), Some(230));
+
+        // The mode survives `init`.
+        reg.init();
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+        }
+        assert!(reg.check(150));
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n1), 150);
+        }
+        assert!(!reg.check(160));
+    }
+
+    #[test]
+    fn test_latching_stays_expired_after_feed() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(reg.check(150));
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 160);
+        }
+        assert!(reg.check(170));
+        assert_eq!(reg.expired_at(), Some(150));
+    }
+
+    #[test]
+    fn test_node_elapsed() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 1_000);
+        }
+        assert_eq!(n.elapsed(1_000), 0);
+        assert_eq!(n.elapsed(1_250), 250);
+
+        // Across the wrap boundary.
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), u32::MAX - 9);
+        }
+        assert_eq!(n.elapsed(u32::MAX), 9);
+        assert_eq!(n.elapsed(0), 10);
+        assert_eq!(n.elapsed(20), 30);
+    }
+
+    #[test]
+    fn test_check_report_healthy() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        assert_eq!(
+            reg.check_report(0),
+            CheckReport {
+                expired: false,
+                first_expired_id: None,
+                scanned: 0,
+                expired_at_ms: None,
+            }
+        );
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
+        }
+        assert_eq!(
+            reg.check_report(100),
+            CheckReport {
+                expired: false,
+                first_expired_id: None,
+                scanned: 2,
+                expired_at_ms: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_check_report_expired() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 500, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            reg.add(pin_mut(&mut n3), 100, 0);
+        }
+        let expected = CheckReport {
+            expired: true,
+            first_expired_id: Some(2),
+            scanned: 3,
+            expired_at_ms: Some(150),
+        };
+        assert_eq!(reg.check_report(150), expected);
+
+        // Latched: detection time and id stay frozen.
+        assert_eq!(reg.check_report(600), expected);
+    }
+
+    #[test]
+    fn test_feed_to_tail_reports_stalest_first() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            reg.add(pin_mut(&mut n3), 100, 0);
+        }
+        reg.set_feed_to_tail(true);
+
+        unsafe {
+            assert!(reg.feed_checked(pin_mut(&mut n1), 20));
+        }
+        assert!(reg.iter_ids().eq([2, 3, 1]));
+        assert!(reg.feed_by_id(2, 40));
+        assert!(reg.iter_ids().eq([3, 1, 2]));
+        // Feeding the tail keeps the order.
+        assert!(reg.feed_by_id(2, 50));
+        assert!(reg.iter_ids().eq([3, 1, 2]));
+        assert!(reg.verify_integrity(8));
+
+        assert!(reg.check(200));
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(3));
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+        assert_eq!(reg.next_expired(&mut cursor), Some(2));
+        assert_eq!(reg.next_expired(&mut cursor), None);
+    }
+
+    #[test]
+    fn test_feed_keeps_order_by_default() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            assert!(reg.feed_checked(pin_mut(&mut n1), 20));
+        }
+        assert!(reg.iter_ids().eq([1, 2]));
+    }
+
+    #[test]
+    fn test_nearest_deadline() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+        let mut n3 = WatchdogNode::default();
+
+        assert_eq!(reg.nearest_deadline(0), None);
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 500, 0);
+            reg.add(pin_mut(&mut n2), 300, 100);
+            reg.add(pin_mut(&mut n3), 1_000, 0);
+        }
+        assert_eq!(reg.nearest_deadline(200), Some(200));
+
+        // An expired node saturates to zero.
+        assert_eq!(reg.nearest_deadline(450), Some(0));
+    }
+
+    #[test]
+    fn test_nearest_deadline_follows_expiry_rule() {
+        let mut reg = WatchdogRegistry::new();
+        let mut timed = WatchdogNode::builder().id(1).build();
+        let mut counted = WatchdogNode::builder().id(2).build();
+
+        reg.set_grace_ms(50);
+        unsafe {
+            reg.add(pin_mut(&mut timed), 100, 0);
+            reg.add_by_misses(pin_mut(&mut counted), 3, 0);
+        }
+
+        // The miss-budget node has no time-based margin.
+        assert_eq!(reg.worst_margin(120), Some((1, 30)));
+        assert_eq!(reg.nearest_deadline(120), Some(30));
+        assert!(!reg.check(150));
+        assert!(reg.check(151));
+        assert_eq!(reg.nearest_deadline(151), Some(0));
+
+        unsafe {
+            reg.remove(pin_mut(&mut timed));
+        }
+        assert_eq!(reg.worst_margin(200), None);
+    }
+
+    #[test]
+    fn test_nearest_deadline_wraps() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, u32::MAX - 20);
+        }
+        assert_eq!(reg.nearest_deadline(9), Some(70));
+    }
+
+    #[test]
+    fn test_remove_and_clear_if_cause() {
+        let mut reg = WatchdogRegistry::new();
+        let mut cause = WatchdogNode::builder().id(1).build();
+        let mut bystander = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut cause), 100, 0);
+            reg.add(pin_mut(&mut bystander), 500, 0);
+        }
+        assert!(reg.check(150));
+
+        unsafe {
+            assert!(!reg.remove_and_clear_if_cause(pin_mut(&mut bystander)));
+        }
+        assert!(!bystander.is_linked());
+        assert!(reg.is_expired());
+
+        unsafe {
+            assert!(reg.remove_and_clear_if_cause(pin_mut(&mut cause)));
+        }
+        assert!(!cause.is_linked());
+        assert!(!reg.is_expired());
+        assert!(!reg.check(1_000));
+    }
+
+    #[test]
+    fn test_remove_and_clear_if_cause_ignores_unregistered() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(1).build();
+        let mut stranger = WatchdogNode::builder().id(1).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(reg.check(150));
+
+        // Same id, but never registered here.
+        unsafe {
+            assert!(!reg.remove_and_clear_if_cause(pin_mut(&mut stranger)));
+        }
+        assert!(reg.is_expired());
+    }
+
+    #[test]
+    fn test_each_mut_doubles_timeouts() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 250, 0);
+        }
+
+        let mut visited = 0;
+        reg.each_mut(|node| {
+            let timeout = node.timeout_interval_ms();
+            WatchdogRegistry::set_timeout(node, timeout * 2);
+            visited += 1;
+        });
+        assert_eq!(visited, 2);
+        assert_eq!(n1.timeout_interval_ms(), 200);
+        assert_eq!(n2.timeout_interval_ms(), 500);
+        assert!(!reg.check(200));
+    }
+
+    #[test]
+    fn test_elapsed_since_across_wrap() {
+        assert_eq!(elapsed_since(50u32, u32::MAX - 49), Some(100));
+        assert_eq!(elapsed_since(0u32, u32::MAX), Some(1));
+        assert_eq!(elapsed_since(u32::MAX, u32::MAX - 10), Some(10));
+        assert_eq!(elapsed_since(7u32, 7), Some(0));
+        assert_eq!(elapsed_since(u64::MAX, 0u64), None);
+    }
+
+    #[test]
+    fn test_elapsed_since_future_is_none() {
+        // `then` just after `now`, on both sides of the wrap boundary.
+        assert_eq!(elapsed_since(100u32, 101), None);
+        assert_eq!(elapsed_since(u32::MAX, 5), None);
+        assert_eq!(elapsed_since(u32::MAX - 5, u32::MAX), None);
+    }
+
+    #[test]
+    fn test_elapsed_since_half_range_boundary() {
+        assert_eq!(elapsed_since(u32::MAX / 2, 0), Some(u32::MAX / 2));
+        assert_eq!(elapsed_since(u32::MAX / 2 + 1, 0), None);
+        assert_eq!(
+            elapsed_since(10u32, 10u32.wrapping_sub(u32::MAX / 2)),
+            Some(u32::MAX / 2)
+        );
+    }
+
+    #[test]
+    fn test_check_ignores_feed_after_now_across_wrap() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        // Fed at a timestamp past the one passed to `check`, which wraps.
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 10);
+        }
+        assert!(!reg.check(u32::MAX - 10));
+        assert!(!reg.check(110));
+        assert!(reg.check(111));
+    }
+
+    #[test]
+    fn test_scans_ignore_feed_after_now() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 10);
+            WatchdogRegistry::set_warn_interval(pin_mut(&mut n), 50);
+        }
+
+        // `now` was read just before the node was fed.
+        let now = u32::MAX - 10;
+        assert_eq!(reg.health(now), Health::Ok);
+        assert_eq!(reg.worst_margin(now), Some((0, 100)));
+        let mut out = [NodeStatus::default(); 1];
+        reg.snapshot(now, &mut out);
+        assert!(!out[0].expired);
+        assert_eq!(out[0].remaining_ms, 100);
+        assert!(!reg.check_group(0, now));
+        assert_eq!(reg.check_all(now), 0);
+        assert!(!reg.is_expired());
+    }
+
+    #[test]
+    fn test_misses_expire_after_exactly_max_missed() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(1).build();
+
+        unsafe {
+            reg.add_by_misses(pin_mut(&mut n), 3, 0);
+        }
+        // The first check still sees the registration as the last feed.
+        assert!(!reg.check(1));
+        assert_eq!(n.missed_checks(), 0);
+        assert!(!reg.check(2));
+        assert!(!reg.check(3));
+        assert_eq!(n.missed_checks(), 2);
+        assert!(reg.check(4));
+        assert_eq!(n.missed_checks(), 3);
+
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+    }
+
+    #[test]
+    fn test_misses_counted_behind_expired_node() {
+        let mut reg = WatchdogRegistry::new_non_latching();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add_by_misses(pin_mut(&mut n2), 2, 0);
+        }
+        assert_eq!(reg.head_ptr(), &raw const n1);
+
+        // `n1` stays expired, so every check stops at the head.
+        assert!(reg.check(200));
+        assert_eq!(n2.missed_checks(), 0);
+        assert!(reg.check(300));
+        assert_eq!(n2.missed_checks(), 1);
+        assert!(reg.check(400));
+        assert_eq!(n2.missed_checks(), 2);
+
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+        assert_eq!(reg.next_expired(&mut cursor), Some(2));
+    }
+
+    #[test]
+    fn test_misses_budget_one_fed_every_tick_never_expires() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add_by_misses(pin_mut(&mut n), 1, 0);
+        }
+        for tick in 1..=5 {
+            unsafe {
+                WatchdogRegistry::feed(pin_mut(&mut n), tick * 10 - 5);
             }
-            // SAFETY: `current` is non-null and points to a valid node in
-            // the list (all nodes are pinned and alive by API contract).
-            current = unsafe { (*current).next };
+            assert!(!reg.check(tick * 10));
+            assert_eq!(n.missed_checks(), 0);
+        }
+
+        // One tick without a feed is the whole budget.
+        assert!(reg.check(60));
+    }
+
+    #[test]
+    fn test_add_by_misses_rejected_at_capacity() {
+        let mut reg = WatchdogRegistry::with_capacity(1);
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add_by_misses(pin_mut(&mut n2), 3, 0);
+        }
+        assert!(!n2.is_linked());
+        assert_eq!(n2.max_missed, 0);
+    }
+
+    #[test]
+    fn test_misses_one_fewer_than_budget_is_healthy() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add_by_misses(pin_mut(&mut n), 3, 0);
+        }
+        // Time does not matter, only the number of checks without a feed.
+        for now in [10_000, 20_000, 30_000] {
+            assert!(!reg.check(now));
+        }
+        assert_eq!(n.missed_checks(), 2);
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 30_000);
+        }
+        assert_eq!(n.missed_checks(), 0);
+        for now in [40_000, 50_000, 60_000] {
+            assert!(!reg.check(now));
+        }
+        assert!(reg.check(70_000));
+    }
+
+    #[test]
+    fn test_misses_reset_by_add_and_ignored_when_paused() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add_by_misses(pin_mut(&mut n), 0, 0);
+            WatchdogRegistry::pause(pin_mut(&mut n));
+        }
+        assert_eq!(n.max_missed, 1);
+        // A zero budget behaves like one, but paused nodes do not count.
+        assert!(!reg.check(1));
+        assert_eq!(n.missed_checks(), 0);
+
+        // A regular add turns the node back into a time-based watchdog.
+        unsafe {
+            WatchdogRegistry::resume(pin_mut(&mut n), 1);
+            reg.add(pin_mut(&mut n), 100, 1);
+        }
+        assert!(!reg.check(2));
+        assert!(!reg.check(101));
+        assert!(reg.check(102));
+    }
+
+    #[test]
+    fn test_head_ptr_tracks_list_head() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        assert!(reg.head_ptr().is_null());
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+        }
+        assert_eq!(reg.head_ptr(), &raw const n1);
+
+        unsafe {
+            reg.remove(pin_mut(&mut n1));
+        }
+        assert_eq!(reg.head_ptr(), &raw const n2);
+    }
+
+    #[test]
+    fn test_seed_latch_matches_check() {
+        let mut checked = WatchdogRegistry::new();
+        let mut seeded = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            checked.add(pin_mut(&mut n1), 100, 0);
+            seeded.add(pin_mut(&mut n2), 100, 0);
+        }
+        assert!(checked.check(150));
+        seeded.seed_latch(150, 2);
+
+        assert!(seeded.is_expired());
+        assert!(seeded.check(150));
+        assert_eq!(seeded.expired_at(), checked.expired_at());
+        assert_eq!(seeded.first_expired_id(), Some(2));
+        assert_eq!(seeded.next_expired(&mut Cursor::start()), Some(2));
+
+        // An existing latch is kept.
+        seeded.seed_latch(300, 9);
+        assert_eq!(seeded.expired_at(), Some(150));
+        assert_eq!(seeded.first_expired_id(), Some(2));
+
+        seeded.clear_expired();
+        assert_eq!(seeded.expired_at(), None);
+    }
+
+    #[test]
+    fn test_feed_timed_returns_gap() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, u32::MAX - 10);
+        }
+        assert_eq!(reg.feed_timed(unsafe { pin_mut(&mut n) }, 20), Some(31));
+        assert_eq!(reg.feed_timed(unsafe { pin_mut(&mut n) }, 70), Some(50));
+        assert_eq!(n.last_touched_timestamp_ms, 70);
+    }
+
+    #[test]
+    fn test_feed_timed_unregistered_is_none() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        assert_eq!(reg.feed_timed(unsafe { pin_mut(&mut n) }, 50), None);
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+            reg.remove(pin_mut(&mut n));
+        }
+        assert_eq!(reg.feed_timed(unsafe { pin_mut(&mut n) }, 80), None);
+        assert_eq!(n.last_touched_timestamp_ms, 0);
+    }
+
+    #[test]
+    fn test_degraded_recovers_after_n_healthy_scans() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder()
+            .timeout_ms(100)
+            .warn_interval_ms(50)
+            .build();
+
+        unsafe {
+            WatchdogRegistry::set_recover_after(pin_mut(&mut n), 3);
+            reg.add_configured(pin_mut(&mut n), 0);
+        }
+        assert!(!WatchdogRegistry::degraded(unsafe { pin_ref(&n) }));
+
+        // Enter the warning band: degraded.
+        reg.update_health(60);
+        assert!(WatchdogRegistry::degraded(unsafe { pin_ref(&n) }));
+
+        // Fed and healthy for N-1 scans: still degraded.
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 60);
+        }
+        reg.update_health(70);
+        reg.update_health(80);
+        assert!(WatchdogRegistry::degraded(unsafe { pin_ref(&n) }));
+
+        // The Nth healthy scan clears it.
+        reg.update_health(90);
+        assert!(!WatchdogRegistry::degraded(unsafe { pin_ref(&n) }));
+        assert!(!reg.is_expired());
+    }
+
+    #[test]
+    fn test_degraded_streak_restarts_on_relapse() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            WatchdogRegistry::set_recover_after(pin_mut(&mut n), 2);
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+
+        reg.update_health(150); // expired
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 150);
+        }
+        reg.update_health(160);
+        reg.update_health(300); // expired again before recovering
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 300);
+        }
+        reg.update_health(310);
+        assert!(WatchdogRegistry::degraded(unsafe { pin_ref(&n) }));
+        reg.update_health(320);
+        assert!(!WatchdogRegistry::degraded(unsafe { pin_ref(&n) }));
+    }
+
+    #[test]
+    fn test_check_first_expired_healthy() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
         }
+        assert_eq!(reg.check_first_expired(100), None);
+        assert!(!reg.is_expired());
+    }
+
+    #[test]
+    fn test_check_first_expired_returns_id_and_latches() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
 
-        // Node is not in the list — initialize fields and prepend.
-        // SAFETY: `node_ptr` points to a valid, pinned `WatchdogNode`.
         unsafe {
-            (*node_ptr).last_touched_timestamp_ms = now;
-            (*node_ptr).timeout_interval_ms = timeout_ms;
-            (*node_ptr).next = self.head;
+            reg.add(pin_mut(&mut n1), 500, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
         }
-        self.head = node_ptr;
+        assert_eq!(reg.check_first_expired(150), Some(2));
+        assert!(reg.is_expired());
+        assert_eq!(reg.expired_at(), Some(150));
+
+        // Latched: later calls keep reporting against the snapshot.
+        assert_eq!(reg.check_first_expired(1000), Some(2));
+        assert_eq!(reg.expired_at(), Some(150));
     }
 
-    /// Remove a previously registered watchdog from the registry.
-    ///
-    /// Walks the linked list, finds the node by raw pointer address, unlinks
-    /// it, and clears its `next` pointer. If the node is not found the call
-    /// is a no-op.
-    ///
-    /// # Parameters
-    /// - `node`: a pinned mutable reference to the watchdog node to remove.
-    pub fn remove(&mut self, node: Pin<&mut WatchdogNode>) {
-        // SAFETY: We only read the address; we do not move the node.
-        let node_ptr: *mut WatchdogNode = unsafe { &raw mut *node.get_unchecked_mut() };
+    #[test]
+    fn test_window_feed_too_fast() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(1).build();
 
-        let mut prev: *mut WatchdogNode = ptr::null_mut();
-        let mut current = self.head;
+        unsafe {
+            reg.add(pin_mut(&mut n), 0, 0);
+            WatchdogRegistry::set_window(pin_mut(&mut n), 50, 200);
+            // First feed after add is never too early.
+            WatchdogRegistry::feed(pin_mut(&mut n), 10);
+        }
+        assert!(!reg.check(20));
 
-        while !current.is_null() {
-            if current == node_ptr {
-                if prev.is_null() {
-                    // Removing the head of the list.
-                    // SAFETY: `current` (== `node_ptr`) is valid and in the
-                    // list. Reading its `next` field is safe.
-                    self.head = unsafe { (*current).next };
-                } else {
-                    // Removing from the middle or tail.
-                    // SAFETY: `prev` is non-null and was set to a valid node
-                    // pointer in a previous iteration. `current` is valid.
-                    unsafe {
-                        (*prev).next = (*current).next;
-                    }
-                }
-                // Clear the removed node's next pointer.
-                // SAFETY: `node_ptr` is valid (pinned and alive).
-                unsafe {
-                    (*node_ptr).next = ptr::null_mut();
-                }
-                return;
-            }
-            prev = current;
-            // SAFETY: `current` is non-null, valid, and in the list.
-            current = unsafe { (*current).next };
+        unsafe {
+            WatchdogRegistry::feed(pin_mut(&mut n), 30);
         }
+        assert!(reg.check(40));
+
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
     }
 
-    /// Feed (touch) a watchdog, resetting its timestamp to `now`.
-    ///
-    /// Must be called periodically by the owning task to signal liveness.
-    /// This is a static method — it does not require `&mut self` because it
-    /// only writes to the node itself, not to the registry.
-    ///
-    /// # Parameters
-    /// - `node`: a pinned mutable reference to the watchdog node to feed.
-    /// - `now`: the current timestamp in milliseconds.
-    pub fn feed(node: Pin<&mut WatchdogNode>, now: u32) {
-        // SAFETY: We are writing to a field of the pinned node. We do not
-        // move the node. The caller guarantees the node is alive.
+    #[test]
+    fn test_window_feed_in_window() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
         unsafe {
-            node.get_unchecked_mut().last_touched_timestamp_ms = now;
+            reg.add(pin_mut(&mut n), 0, 0);
+            WatchdogRegistry::set_window(pin_mut(&mut n), 50, 200);
+            WatchdogRegistry::feed(pin_mut(&mut n), 100);
+            WatchdogRegistry::feed(pin_mut(&mut n), 150);
         }
+        assert_eq!(n.min_interval_ms(), 50);
+        assert_eq!(n.timeout_interval_ms(), 200);
+        assert!(!reg.check(300));
     }
 
-    /// Assign a user-defined identifier to a watchdog node.
-    ///
-    /// The identifier can be set at any time — before or after adding the
-    /// node to a registry. It is never modified by the library; it is purely
-    /// for the caller to identify expired nodes via
-    /// [`next_expired`](Self::next_expired).
-    ///
-    /// # Parameters
-    /// - `node`: a pinned mutable reference to the watchdog node.
-    /// - `id`: the identifier to assign.
-    pub fn assign_id(node: Pin<&mut WatchdogNode>, id: u32) {
-        // SAFETY: Writing to a field; not moving the node.
+    #[test]
+    fn test_window_feed_too_slow() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
         unsafe {
-            node.get_unchecked_mut().id = id;
+            reg.add(pin_mut(&mut n), 0, 0);
+            WatchdogRegistry::set_window(pin_mut(&mut n), 50, 200);
+            WatchdogRegistry::feed(pin_mut(&mut n), 100);
         }
+        assert!(!reg.check(300));
+        assert!(reg.check(301));
     }
 
-    /// Check all registered watchdogs for expiration.
-    ///
-    /// Iterates the linked list of registered watchdogs. For each one,
-    /// computes elapsed time using wrapping arithmetic (safe across `u32`
-    /// overflow) and compares against the timeout interval.
-    ///
-    /// Once an expiration is detected the registry latches into the expired
-    /// state: all subsequent calls return `true` without re-scanning the
-    /// list, and `expired_at_ms` is frozen at the timestamp of first
-    /// detection.
-    ///
-    /// # Parameters
-    /// - `now`: the current timestamp in milliseconds.
-    ///
-    /// # Returns
-    /// `true` if any watchdog has expired, `false` if all are healthy.
-    pub fn check(&mut self, now: u32) -> bool {
-        if self.expired {
-            return true;
+    #[test]
+    fn test_merge_two_registries() {
+        let mut reg = WatchdogRegistry::new();
+        let mut other = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+        let mut n4 = WatchdogNode::builder().id(4).build();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            other.add(pin_mut(&mut n3), 100, 0);
+            other.add(pin_mut(&mut n4), 100, 0);
         }
 
-        let mut current = self.head;
-        while !current.is_null() {
-            // SAFETY: `current` is non-null and points to a valid, pinned
-            // node in the list. We only read fields — no mutation, no move.
-            let node = unsafe { &*current };
-            let elapsed = now.wrapping_sub(node.last_touched_timestamp_ms);
+        assert_eq!(reg.merge(&mut other), Ok(()));
 
-            if elapsed > node.timeout_interval_ms {
-                self.expired = true;
-                self.expired_at_ms = now;
-                return true;
-            }
+        assert_eq!(count_nodes(reg.head), 4);
+        assert!(reg.iter_ids().eq([1, 2, 3, 4]));
+        assert!(other.head.is_null());
+        assert!(other.tail.is_null());
 
-            current = node.next;
+        // The tail was updated: a new node lands after the merged ones.
+        let mut n5 = WatchdogNode::builder().id(5).build();
+        unsafe {
+            reg.add(pin_mut(&mut n5), 100, 0);
         }
-
-        false
+        assert!(reg.iter_ids().eq([1, 2, 3, 4, 5]));
     }
 
-    /// Get the next expired watchdog node in the iteration.
-    ///
-    /// This method implements a cursor-based iterator over the linked list.
-    /// On each call it resumes from the position stored in `*cursor` and
-    /// scans forward for the next node whose elapsed time exceeds its
-    /// timeout interval.
-    ///
-    /// The evaluation uses the `expired_at_ms` timestamp snapshot captured by
-    /// [`check`](Self::check), so nodes are compared against the same point
-    /// in time that triggered the expiration.  A half-range guard filters
-    /// out nodes whose [`feed`](Self::feed) timestamp is *ahead* of the
-    /// snapshot (i.e. they were fed between `check` and this method),
-    /// preventing `wrapping_sub` underflow from being misinterpreted as a
-    /// large elapsed time.
-    ///
-    /// # Parameters
-    /// - `cursor`: a mutable reference to a raw pointer that tracks iteration
-    ///   state. The caller must initialize it to [`core::ptr::null()`] before
-    ///   the first call. The method advances the cursor to the found node on
-    ///   success.
-    ///
-    /// # Returns
-    /// - `Some(id)` if an expired node was found.
-    /// - `None` when no more expired nodes remain, or if [`check`](Self::check)
-    ///   has not yet detected an expiration.
-    ///
-    /// # Example
-    ///
-    /// ```rust,no_run
-    /// # use mwdg::WatchdogRegistry;
-    /// # let mut registry = WatchdogRegistry::new();
-    /// # let now = 0u32;
-    /// if registry.check(now) {
-    ///     let mut cursor = core::ptr::null();
-    ///     while let Some(id) = registry.next_expired(&mut cursor) {
-    ///         // handle expired watchdog `id`
-    ///     }
-    /// }
-    /// ```
-    pub fn next_expired(&self, cursor: &mut *const WatchdogNode) -> Option<u32> {
-        if !self.expired {
-            return None;
+    #[test]
+    fn test_merge_ors_latch() {
+        let mut reg = WatchdogRegistry::new();
+        let mut other = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().id(7).build();
+
+        unsafe {
+            other.add(pin_mut(&mut n), 100, 0);
         }
+        assert!(other.check(150));
 
-        let now = self.expired_at_ms;
+        assert_eq!(reg.merge(&mut other), Ok(()));
 
-        // Determine start position: if cursor is null we start from the head
-        // of the list; otherwise from the node after the cursor.
-        let start = if (*cursor).is_null() {
-            self.head.cast_const()
-        } else {
-            // SAFETY: `*cursor` is non-null and was previously set by this
-            // method to point to a valid registered node.
-            unsafe { (*(*cursor)).next.cast_const() }
-        };
+        assert!(reg.is_expired());
+        assert_eq!(reg.expired_at(), Some(150));
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(7));
+    }
 
-        let mut current = start;
-        while !current.is_null() {
-            // SAFETY: `current` is non-null and points to a valid, pinned
-            // node in the list. We only read fields.
-            let node = unsafe { &*current };
-            let elapsed = now.wrapping_sub(node.last_touched_timestamp_ms);
-
-            // The half-range guard (`elapsed <= u32::MAX / 2`) filters out
-            // nodes that were fed *after* the `expired_at_ms` snapshot was
-            // taken.  In that case `wrapping_sub` underflows and produces a
-            // value in the upper half of the u32 range, which would otherwise
-            // be misinterpreted as an enormous elapsed time.
-            if elapsed <= u32::MAX / 2 && elapsed > node.timeout_interval_ms {
-                *cursor = current;
-                return Some(node.id);
-            }
+    #[test]
+    fn test_is_linked_add_remove() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
 
-            current = node.next.cast_const();
+        assert!(!n1.is_linked());
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
         }
+        assert!(n1.is_linked());
+        // The tail has a null `next` but is still linked.
+        assert!(n2.next.is_null());
+        assert!(n2.is_linked());
 
-        None
+        unsafe {
+            reg.remove(pin_mut(&mut n1));
+        }
+        assert!(!n1.is_linked());
+        assert!(n2.is_linked());
+
+        reg.remove_all();
+        assert!(!n2.is_linked());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use core::ptr;
+    #[test]
+    fn test_is_linked_cleared_by_one_shot_expiry() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::builder().one_shot(true).build();
 
-    /// Helper: create a pinned mutable reference from a mutable reference.
-    ///
-    /// # Safety
-    /// The caller must not move the referenced value after calling this.
-    /// In tests we own the nodes on the stack and never move them, so this
-    /// is safe.
-    unsafe fn pin_mut(node: &mut WatchdogNode) -> Pin<&mut WatchdogNode> {
-        unsafe { Pin::new_unchecked(node) }
+        unsafe {
+            reg.add(pin_mut(&mut n), 100, 0);
+        }
+        assert!(reg.check(150));
+        assert!(!n.is_linked());
     }
 
-    /// Helper: count nodes reachable from `head`.
-    fn count_nodes(head: *const WatchdogNode) -> u32 {
-        let mut count = 0u32;
-        let mut current = head;
-        while !current.is_null() {
-            count += 1;
-            // SAFETY: `current` is non-null and points to a valid node.
-            current = unsafe { (*current).next as *const WatchdogNode };
+    #[test]
+    fn test_add_all_watchdog_table() {
+        // Built in a const context, as for a ROM table.
+        const TABLE: [WatchdogNode; 3] = watchdog_table![(1, 100), (2, 200), (3, 300)];
+
+        let mut reg = WatchdogRegistry::new();
+        let mut table = core::pin::pin!(TABLE);
+        reg.add_all(table.as_mut(), 10);
+
+        assert_eq!(count_nodes(reg.head), 3);
+        let mut expected = [(1, 100), (2, 200), (3, 300)].into_iter();
+        for node in &reg {
+            let (id, timeout) = expected.next().unwrap();
+            assert_eq!(node.id(), id);
+            assert_eq!(node.timeout_interval_ms(), timeout);
+            assert_eq!(node.last_touched_timestamp_ms(), 10);
+            assert!(node.is_linked());
         }
-        count
+        assert!(expected.next().is_none());
     }
 
     #[test]
-    fn test_add_single_node() {
+    fn test_drain_yields_all_and_empties() {
         let mut reg = WatchdogRegistry::new();
-        let mut n = WatchdogNode::default();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
 
-        unsafe { reg.add(pin_mut(&mut n), 100, 0) };
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            reg.add(pin_mut(&mut n3), 100, 0);
+        }
 
-        assert_eq!(count_nodes(reg.head), 1);
-        assert_eq!(n.timeout_interval_ms, 100);
-        assert_eq!(n.last_touched_timestamp_ms, 0);
+        let mut ids = [0; 3];
+        for (slot, node) in ids.iter_mut().zip(reg.drain()) {
+            assert!(node.next.is_null());
+            assert!(!node.is_linked());
+            *slot = node.id();
+        }
+
+        assert_eq!(ids, [1, 2, 3]);
+        assert!(reg.head.is_null());
+        assert!(reg.tail.is_null());
     }
 
     #[test]
-    fn test_add_multiple_nodes() {
+    fn test_drain_dropped_early_still_empties() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+
+        unsafe {
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+        }
+
+        assert!(reg.drain().next().is_some());
+        assert!(reg.head.is_null());
+        assert!(!n2.is_linked());
+    }
+
+    #[test]
+    fn test_check_all_none_expired() {
         let mut reg = WatchdogRegistry::new();
         let mut n1 = WatchdogNode::default();
         let mut n2 = WatchdogNode::default();
-        let mut n3 = WatchdogNode::default();
 
         unsafe {
             reg.add(pin_mut(&mut n1), 100, 0);
             reg.add(pin_mut(&mut n2), 200, 0);
-            reg.add(pin_mut(&mut n3), 300, 0);
         }
 
-        assert_eq!(count_nodes(reg.head), 3);
-        // Prepend order: head -> n3 -> n2 -> n1
-        assert_eq!(reg.head, &mut n3 as *mut WatchdogNode);
+        assert_eq!(reg.check_all(100), 0);
+        assert!(!reg.is_expired());
     }
 
     #[test]
-    fn test_add_duplicate_acts_as_feed() {
+    fn test_check_all_one_expired() {
         let mut reg = WatchdogRegistry::new();
-        let mut n = WatchdogNode::default();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
 
         unsafe {
-            reg.add(pin_mut(&mut n), 100, 10);
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 200, 0);
         }
-        assert_eq!(n.last_touched_timestamp_ms, 10);
-        assert_eq!(n.timeout_interval_ms, 100);
 
-        // Re-add with new timeout and timestamp
+        assert_eq!(reg.check_all(150), 1);
+        assert!(reg.is_expired());
+        assert_eq!(reg.expired_at_ms, 150);
+    }
+
+    #[test]
+    fn test_check_all_three_expired() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+        let mut n3 = WatchdogNode::default();
+        let mut n4 = WatchdogNode::default();
+
         unsafe {
-            reg.add(pin_mut(&mut n), 250, 50);
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 120, 0);
+            reg.add(pin_mut(&mut n3), 140, 0);
+            reg.add(pin_mut(&mut n4), 500, 0); // healthy
         }
-        assert_eq!(n.last_touched_timestamp_ms, 50);
-        assert_eq!(n.timeout_interval_ms, 250);
-        // Should still be just one node
-        assert_eq!(count_nodes(reg.head), 1);
+
+        assert_eq!(reg.check_all(200), 3);
+        assert_eq!(reg.expired_at_ms, 200);
+
+        // Still counts on subsequent scans, but the snapshot stays frozen.
+        assert_eq!(reg.check_all(300), 3);
+        assert_eq!(reg.expired_at_ms, 200);
     }
 
     #[test]
-    fn test_add_preserves_user_id() {
+    fn test_check_wrapping_time_healthy() {
         let mut reg = WatchdogRegistry::new();
         let mut n = WatchdogNode::default();
 
+        // Feed near u32::MAX
         unsafe {
-            WatchdogRegistry::assign_id(pin_mut(&mut n), 42);
-            reg.add(pin_mut(&mut n), 100, 0);
+            reg.add(pin_mut(&mut n), 200, u32::MAX - 50);
         }
-        assert_eq!(n.id, 42, "add must not overwrite a pre-set id");
+
+        // Time wraps around: now = 100 → elapsed = 100 - (MAX-50) wrapping = 151
+        // 151 <= 200 → healthy
+        assert!(!reg.check(100));
     }
 
     #[test]
-    fn test_readd_preserves_user_id() {
+    fn test_check_wrapping_time_expired() {
         let mut reg = WatchdogRegistry::new();
         let mut n = WatchdogNode::default();
 
+        // Feed near u32::MAX
         unsafe {
-            WatchdogRegistry::assign_id(pin_mut(&mut n), 7);
-            reg.add(pin_mut(&mut n), 100, 0);
-            reg.add(pin_mut(&mut n), 200, 50);
+            reg.add(pin_mut(&mut n), 200, u32::MAX - 50);
         }
-        assert_eq!(n.id, 7, "re-add must not overwrite the id field");
+
+        // Time wraps around: now = 300 → elapsed = 300 - (MAX-50) wrapping = 351
+        // 351 > 200 → expired
+        assert!(reg.check(300));
     }
 
     #[test]
-    fn test_remove_single_node() {
-        let mut reg = WatchdogRegistry::new();
-        let mut n = WatchdogNode::default();
+    fn test_check_u64_wrapping_time_healthy() {
+        let mut reg = WatchdogRegistryT::<u64>::new();
+        let mut n = WatchdogNodeT::<u64>::default();
 
+        // Feed near u64::MAX
         unsafe {
-            reg.add(pin_mut(&mut n), 100, 0);
+            reg.add(pin_mut(&mut n), 200, u64::MAX - 50);
         }
-        assert_eq!(count_nodes(reg.head), 1);
 
-        unsafe {
-            reg.remove(pin_mut(&mut n));
-        }
-        assert_eq!(count_nodes(reg.head), 0);
-        assert!(n.next.is_null());
+        // Time wraps around: now = 100 → elapsed = 151 → healthy
+        assert!(!reg.check(100));
     }
 
     #[test]
-    fn test_remove_head() {
-        let mut reg = WatchdogRegistry::new();
-        let mut n1 = WatchdogNode::default();
-        let mut n2 = WatchdogNode::default();
+    fn test_check_u64_wrapping_time_expired() {
+        let mut reg = WatchdogRegistryT::<u64>::new();
+        let mut n = WatchdogNodeT::<u64>::default();
 
+        // Feed near u64::MAX
         unsafe {
-            reg.add(pin_mut(&mut n1), 100, 0);
-            reg.add(pin_mut(&mut n2), 200, 0);
+            reg.add(pin_mut(&mut n), 200, u64::MAX - 50);
         }
-        // head -> n2 -> n1
-        assert_eq!(count_nodes(reg.head), 2);
+
+        // Time wraps around: now = 300 → elapsed = 351 → expired
+        assert!(reg.check(300));
+        assert_eq!(reg.expired_at_ms, 300);
+    }
+
+    #[test]
+    fn test_check_u64_beyond_u32_range() {
+        let mut reg = WatchdogRegistryT::<u64>::new();
+        let mut n = WatchdogNodeT::<u64>::default();
+        let start = u64::from(u32::MAX) + 1_000;
 
         unsafe {
-            reg.remove(pin_mut(&mut n2));
+            reg.add(pin_mut(&mut n), 200, start);
         }
-        assert_eq!(count_nodes(reg.head), 1);
-        assert_eq!(reg.head, &mut n1 as *mut WatchdogNode);
+
+        // A `u32` clock would have wrapped here; the `u64` one just counts on.
+        assert!(!reg.check(start + 200));
+        assert!(reg.check(start + 201));
     }
 
     #[test]
-    fn test_remove_from_middle() {
-        let mut reg = WatchdogRegistry::new();
-        let mut n1 = WatchdogNode::default();
-        let mut n2 = WatchdogNode::default();
-        let mut n3 = WatchdogNode::default();
+    fn test_next_expired_u64_skips_node_fed_after_snapshot() {
+        let mut reg = WatchdogRegistryT::<u64>::new();
+        let mut n1 = WatchdogNodeT::<u64>::default();
+        let mut n2 = WatchdogNodeT::<u64>::default();
 
         unsafe {
+            WatchdogRegistryT::assign_id(pin_mut(&mut n1), 1);
+            WatchdogRegistryT::assign_id(pin_mut(&mut n2), 2);
+
             reg.add(pin_mut(&mut n1), 100, 0);
             reg.add(pin_mut(&mut n2), 200, 0);
-            reg.add(pin_mut(&mut n3), 300, 0);
+            WatchdogRegistryT::feed(pin_mut(&mut n2), 350);
         }
-        // head -> n3 -> n2 -> n1
-        assert_eq!(count_nodes(reg.head), 3);
 
+        assert!(reg.check(450));
+
+        // n2 is fed after the snapshot: 450_u64.wrapping_sub(460) is in the
+        // upper half of the u64 range and must be ignored.
         unsafe {
-            reg.remove(pin_mut(&mut n2));
+            WatchdogRegistryT::feed(pin_mut(&mut n2), 460);
         }
-        assert_eq!(count_nodes(reg.head), 2);
-        assert!(n2.next.is_null());
-        // n3 -> n1
-        assert_eq!(reg.head, &mut n3 as *mut WatchdogNode);
-        assert_eq!(n3.next, &mut n1 as *mut WatchdogNode);
+
+        let mut cursor = CursorT::<u64>::start();
+        assert_eq!(reg.next_expired(&mut cursor), Some(1));
+        assert_eq!(reg.next_expired(&mut cursor), None);
     }
 
     #[test]
-    fn test_remove_not_found_is_noop() {
-        let mut reg = WatchdogRegistry::new();
-        let mut n1 = WatchdogNode::default();
-        let mut n2 = WatchdogNode::default();
+    fn test_time_to_expiry_u64_wrapping_time() {
+        let mut reg = WatchdogRegistryT::<u64>::new();
+        let mut n = WatchdogNodeT::<u64>::default();
 
         unsafe {
-            reg.add(pin_mut(&mut n1), 100, 0);
-        }
-        // Try removing a node that was never added
-        unsafe {
-            reg.remove(pin_mut(&mut n2));
+            reg.add(pin_mut(&mut n), 200, u64::MAX - 50);
         }
-        assert_eq!(count_nodes(reg.head), 1);
+
+        assert_eq!(
+            WatchdogRegistryT::time_to_expiry(unsafe { pin_ref(&n) }, 100),
+            49
+        );
+        assert_eq!(
+            WatchdogRegistryT::time_to_expiry(unsafe { pin_ref(&n) }, 300),
+            0
+        );
     }
 
     #[test]
-    fn test_remove_idempotent() {
+    fn test_check_backward_jump_wrapping_mode() {
         let mut reg = WatchdogRegistry::new();
-        let mut n1 = WatchdogNode::default();
-        let mut n2 = WatchdogNode::default();
-        let mut n3 = WatchdogNode::default();
+        let mut n = WatchdogNode::default();
 
         unsafe {
-            reg.add(pin_mut(&mut n1), 100, 0);
-            reg.add(pin_mut(&mut n2), 200, 0);
-            reg.add(pin_mut(&mut n3), 300, 0);
+            reg.add(pin_mut(&mut n), 200, 1000);
         }
 
-        // Remove n3 three times — should not corrupt the list
-        unsafe {
-            reg.remove(pin_mut(&mut n3));
-            reg.remove(pin_mut(&mut n3));
-            reg.remove(pin_mut(&mut n3));
-        }
-        assert_eq!(count_nodes(reg.head), 2);
+        // Clock jumps back by 500 ms: within half the range, the node
+        // looks fed in the future rather than wrapping into a huge elapsed
+        // time (see `elapsed_since`).
+        assert!(!reg.check(500));
 
-        // Remove n1 three times
-        unsafe {
-            reg.remove(pin_mut(&mut n1));
-            reg.remove(pin_mut(&mut n1));
-            reg.remove(pin_mut(&mut n1));
-        }
-        assert_eq!(count_nodes(reg.head), 1);
+        // A jump back by more than half the range still wraps and reads as
+        // a false expiration; only saturating mode absorbs it.
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
 
-        // Remove n2 three times
         unsafe {
-            reg.remove(pin_mut(&mut n2));
-            reg.remove(pin_mut(&mut n2));
-            reg.remove(pin_mut(&mut n2));
+            reg.add(pin_mut(&mut n), 200, 3_000_000_000);
         }
-        assert_eq!(count_nodes(reg.head), 0);
+
+        assert!(reg.check(500));
     }
 
     #[test]
-    fn test_feed_updates_timestamp() {
-        let mut reg = WatchdogRegistry::new();
+    fn test_check_backward_jump_saturating_mode() {
+        let mut reg = WatchdogRegistry::new_saturating();
         let mut n = WatchdogNode::default();
 
         unsafe {
-            reg.add(pin_mut(&mut n), 500, 100);
+            reg.add(pin_mut(&mut n), 200, 1000);
         }
-        assert_eq!(n.last_touched_timestamp_ms, 100);
+
+        // Clock jumps back by 500 ms: treated as zero elapsed.
+        assert!(!reg.check(500));
+        // Regular forward progress is still detected.
+        assert!(!reg.check(1200));
+        assert!(reg.check(1201));
+
+        // Unlike wrapping mode, a jump back by more than half the range is
+        // also treated as zero elapsed.
+        let mut reg = WatchdogRegistry::new_saturating();
+        let mut n = WatchdogNode::default();
 
         unsafe {
-            WatchdogRegistry::feed(pin_mut(&mut n), 350);
+            reg.add(pin_mut(&mut n), 200, 3_000_000_000);
         }
-        assert_eq!(n.last_touched_timestamp_ms, 350);
+
+        assert!(!reg.check(500));
     }
 
     #[test]
-    fn test_feed_preserves_user_id() {
-        let mut reg = WatchdogRegistry::new();
+    fn test_init_preserves_saturating_mode() {
+        let mut reg = WatchdogRegistry::new_saturating();
         let mut n = WatchdogNode::default();
 
+        reg.init();
         unsafe {
-            WatchdogRegistry::assign_id(pin_mut(&mut n), 13);
-            reg.add(pin_mut(&mut n), 100, 0);
-            WatchdogRegistry::feed(pin_mut(&mut n), 50);
+            reg.add(pin_mut(&mut n), 200, 1000);
         }
-        assert_eq!(n.id, 13, "feed must not overwrite the id field");
+        assert!(!reg.check(500));
     }
 
     #[test]
-    fn test_assign_id() {
+    fn test_check_warnings_in_band() {
+        let mut reg = WatchdogRegistry::new();
         let mut n = WatchdogNode::default();
-        assert_eq!(n.id(), 0);
 
         unsafe {
-            WatchdogRegistry::assign_id(pin_mut(&mut n), 42);
+            reg.add(pin_mut(&mut n), 200, 0);
+            WatchdogRegistry::set_warn_interval(pin_mut(&mut n), 150);
         }
-        assert_eq!(n.id(), 42);
+
+        assert!(reg.check_warnings(160));
+        // Warnings neither latch nor affect `check`.
+        assert!(!reg.check(160));
+        assert!(!reg.is_expired());
     }
 
     #[test]
-    fn test_check_healthy() {
+    fn test_check_warnings_healthy() {
         let mut reg = WatchdogRegistry::new();
-        let mut n = WatchdogNode::default();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
 
         unsafe {
-            reg.add(pin_mut(&mut n), 200, 0);
+            reg.add(pin_mut(&mut n1), 200, 0);
+            WatchdogRegistry::set_warn_interval(pin_mut(&mut n1), 150);
+            // Warning disabled (default 0).
+            reg.add(pin_mut(&mut n2), 200, 0);
         }
 
-        // 100 ms elapsed, timeout is 200 — still healthy
-        assert!(!reg.check(100));
+        assert!(!reg.check_warnings(150));
+        assert_eq!(n2.warn_interval_ms(), 0);
     }
 
     #[test]
-    fn test_check_healthy_at_boundary() {
+    fn test_check_warnings_ignores_expired() {
         let mut reg = WatchdogRegistry::new();
         let mut n = WatchdogNode::default();
 
         unsafe {
             reg.add(pin_mut(&mut n), 200, 0);
+            WatchdogRegistry::set_warn_interval(pin_mut(&mut n), 150);
         }
 
-        // Exactly at the timeout boundary — not expired (> required, not >=)
-        assert!(!reg.check(200));
+        assert!(!reg.check_warnings(250));
+        assert!(reg.check(250));
     }
 
     #[test]
-    fn test_check_expired() {
+    fn test_next_expired_iteration() {
         let mut reg = WatchdogRegistry::new();
-        let mut n = WatchdogNode::default();
+        let mut n1 = WatchdogNode::default();
+        let mut n2 = WatchdogNode::default();
+        let mut n3 = WatchdogNode::default();
 
         unsafe {
-            reg.add(pin_mut(&mut n), 100, 0);
+            WatchdogRegistry::assign_id(pin_mut(&mut n1), 1);
+            WatchdogRegistry::assign_id(pin_mut(&mut n2), 2);
+            WatchdogRegistry::assign_id(pin_mut(&mut n3), 3);
+
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 500, 0); // long timeout — healthy
+            reg.add(pin_mut(&mut n3), 100, 0);
         }
+        // list: n1 -> n2 -> n3
 
-        // 200 ms elapsed, timeout is 100 — expired
+        // Trigger expiration at t=200
         assert!(reg.check(200));
+
+        let mut cursor = Cursor::start();
+        let mut expired_ids = [0u32; 4];
+        let mut count = 0;
+
+        while let Some(id) = reg.next_expired(&mut cursor) {
+            expired_ids[count] = id;
+            count += 1;
+            if count >= expired_ids.len() {
+                break;
+            }
+        }
+
+        // n3 (id=3) and n1 (id=1) should be expired; n2 (id=2) is healthy
+        assert_eq!(count, 2);
+        assert_eq!(expired_ids[0], 1); // head is n1
+        assert_eq!(expired_ids[1], 3); // tail is n3
     }
 
     #[test]
-    fn test_check_expired_at_ms_set() {
+    fn test_priority_survives_add_and_feed() {
         let mut reg = WatchdogRegistry::new();
         let mut n = WatchdogNode::default();
 
         unsafe {
+            WatchdogRegistry::set_priority(pin_mut(&mut n), 3);
             reg.add(pin_mut(&mut n), 100, 0);
+            WatchdogRegistry::feed(pin_mut(&mut n), 50);
+            reg.add(pin_mut(&mut n), 200, 60);
         }
-        assert_eq!(reg.expired_at_ms, 0);
+        assert_eq!(n.priority(), 3);
+        assert_eq!(WatchdogNode::builder().priority(9).build().priority(), 9);
+    }
+
+    #[test]
+    fn test_next_expired_with_priority() {
+        let mut reg = WatchdogRegistry::new();
+        let mut critical = WatchdogNode::builder().id(1).priority(2).build();
+        let mut healthy = WatchdogNode::builder().id(2).priority(2).build();
+        let mut best_effort = WatchdogNode::builder().id(3).build();
 
+        unsafe {
+            reg.add(pin_mut(&mut critical), 100, 0);
+            reg.add(pin_mut(&mut healthy), 500, 0);
+            reg.add(pin_mut(&mut best_effort), 100, 0);
+        }
         assert!(reg.check(200));
-        assert_eq!(reg.expired_at_ms, 200);
+
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expired_with_priority(&mut cursor), Some((1, 2)));
+        assert_eq!(reg.next_expired_with_priority(&mut cursor), Some((3, 0)));
+        assert_eq!(reg.next_expired_with_priority(&mut cursor), None);
     }
 
     #[test]
-    fn test_check_latching() {
+    fn test_next_node_visits_all() {
         let mut reg = WatchdogRegistry::new();
-        let mut n = WatchdogNode::default();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        let mut cursor = Cursor::start();
+        assert!(reg.next_node(&mut cursor).is_none());
 
         unsafe {
-            reg.add(pin_mut(&mut n), 100, 0);
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
         }
 
-        assert!(reg.check(200));
-        assert_eq!(reg.expired_at_ms, 200);
+        // Expired or not, every node is visited without a prior `check`.
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_node(&mut cursor).map(|n| n.id()), Some(1));
+        assert_eq!(reg.next_node(&mut cursor).map(|n| n.id()), Some(2));
+        assert!(reg.next_node(&mut cursor).is_none());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_raw_cursor_variants_match_cursor() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
 
-        // Feed the node so it would be healthy again...
         unsafe {
-            WatchdogRegistry::feed(pin_mut(&mut n), 300);
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
+            WatchdogRegistry::set_warn_interval(pin_mut(&mut n1), 50);
+            WatchdogRegistry::set_warn_interval(pin_mut(&mut n2), 50);
         }
 
-        // ...but the registry latches — still expired
-        assert!(reg.check(350));
-        // expired_at_ms should NOT change
-        assert_eq!(reg.expired_at_ms, 200);
+        let (mut cursor, mut raw) = (Cursor::start(), ptr::null());
+        for _ in 0..3 {
+            assert_eq!(
+                reg.next_warning(&mut cursor, 60),
+                reg.next_warning_raw(&mut raw, 60)
+            );
+        }
+        let (mut cursor, mut raw) = (Cursor::start(), ptr::null());
+        for _ in 0..3 {
+            assert_eq!(
+                reg.next_node(&mut cursor).map(|n| n.id()),
+                reg.next_node_raw(&mut raw).map(|n| n.id())
+            );
+        }
+
+        assert!(reg.check(150));
+        let (mut cursor, mut raw) = (Cursor::start(), ptr::null());
+        for _ in 0..3 {
+            assert_eq!(
+                reg.next_expired_with_priority(&mut cursor),
+                reg.next_expired_with_priority_raw(&mut raw)
+            );
+        }
+        let (mut cursor, mut raw) = (Cursor::start(), ptr::null());
+        for _ in 0..3 {
+            assert_eq!(
+                reg.next_expiry_event(&mut cursor),
+                reg.next_expiry_event_raw(&mut raw)
+            );
+        }
+        let (mut cursor, mut raw) = (Cursor::start(), ptr::null());
+        for _ in 0..3 {
+            assert_eq!(
+                reg.next_expired_node(&mut cursor).map(|n| n.id()),
+                reg.next_expired_node_raw(&mut raw).map(|n| n.id())
+            );
+        }
     }
 
     #[test]
-    fn test_check_wrapping_time_healthy() {
+    fn test_cursor_from_foreign_pointer_restarts() {
         let mut reg = WatchdogRegistry::new();
-        let mut n = WatchdogNode::default();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let stranger = WatchdogNode::builder().id(9).build();
 
-        // Feed near u32::MAX
         unsafe {
-            reg.add(pin_mut(&mut n), 200, u32::MAX - 50);
+            reg.add(pin_mut(&mut n1), 100, 0);
         }
+        let mut cursor = Cursor::from_raw(&raw const stranger);
+        assert_eq!(reg.next_node(&mut cursor).map(|n| n.id()), Some(1));
+        assert_eq!(cursor.into_raw(), &raw const n1);
+    }
 
-        // Time wraps around: now = 100 → elapsed = 100 - (MAX-50) wrapping = 151
-        // 151 <= 200 → healthy
-        assert!(!reg.check(100));
+    #[test]
+    fn test_cursor_from_other_registry_restarts() {
+        let mut a = WatchdogRegistry::new();
+        let mut b = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+
+        unsafe {
+            a.add(pin_mut(&mut n1), 100, 0);
+            b.add(pin_mut(&mut n2), 100, 0);
+        }
+        // Both registries are at the same generation, but the cursor was
+        // advanced by `a`, so `b` validates it.
+        let mut cursor = Cursor::start();
+        assert_eq!(a.next_node(&mut cursor).map(|n| n.id()), Some(1));
+        assert_eq!(b.next_node(&mut cursor).map(|n| n.id()), Some(2));
+        assert_eq!(b.next_node(&mut cursor).map(|n| n.id()), None);
     }
 
     #[test]
-    fn test_check_wrapping_time_expired() {
+    fn test_feed_to_tail_relink_invalidates_cursors() {
         let mut reg = WatchdogRegistry::new();
-        let mut n = WatchdogNode::default();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
 
-        // Feed near u32::MAX
+        reg.set_feed_to_tail(true);
         unsafe {
-            reg.add(pin_mut(&mut n), 200, u32::MAX - 50);
+            reg.add(pin_mut(&mut n1), 100, 0);
+            reg.add(pin_mut(&mut n2), 100, 0);
         }
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_node(&mut cursor).map(|n| n.id()), Some(1));
+
+        let generation = reg.generation;
+        assert!(reg.feed_by_id(1, 10));
+        assert_ne!(reg.generation, generation);
+        // `n1` moved to the tail, so the validated cursor has no successor.
+        assert_eq!(reg.next_node(&mut cursor).map(|n| n.id()), None);
+    }
 
-        // Time wraps around: now = 300 → elapsed = 300 - (MAX-50) wrapping = 351
-        // 351 > 200 → expired
-        assert!(reg.check(300));
+    #[test]
+    fn test_cursor_restarts_after_nodes_merged_away() {
+        let mut reg = WatchdogRegistry::new();
+        let mut other = WatchdogRegistry::new();
+        let mut n1 = WatchdogNode::builder().id(1).build();
+        let mut n2 = WatchdogNode::builder().id(2).build();
+        let mut n3 = WatchdogNode::builder().id(3).build();
+
+        unsafe {
+            other.add(pin_mut(&mut n1), 100, 0);
+            other.add(pin_mut(&mut n2), 100, 0);
+        }
+        let mut cursor = Cursor::start();
+        assert_eq!(other.next_node(&mut cursor).map(|n| n.id()), Some(1));
+
+        assert_eq!(reg.merge(&mut other), Ok(()));
+        unsafe {
+            other.add(pin_mut(&mut n3), 100, 0);
+        }
+        assert_eq!(other.next_node(&mut cursor).map(|n| n.id()), Some(3));
+        assert_eq!(other.next_node(&mut cursor).map(|n| n.id()), None);
     }
 
     #[test]
-    fn test_next_expired_iteration() {
+    fn test_next_expired_node_reports_details() {
         let mut reg = WatchdogRegistry::new();
         let mut n1 = WatchdogNode::default();
         let mut n2 = WatchdogNode::default();
-        let mut n3 = WatchdogNode::default();
 
         unsafe {
             WatchdogRegistry::assign_id(pin_mut(&mut n1), 1);
             WatchdogRegistry::assign_id(pin_mut(&mut n2), 2);
-            WatchdogRegistry::assign_id(pin_mut(&mut n3), 3);
-
-            reg.add(pin_mut(&mut n1), 100, 0);
-            reg.add(pin_mut(&mut n2), 500, 0); // long timeout — healthy
-            reg.add(pin_mut(&mut n3), 100, 0);
+            reg.add(pin_mut(&mut n1), 150, 10);
+            reg.add(pin_mut(&mut n2), 500, 0); // healthy
         }
-        // list: n3 -> n2 -> n1
 
-        // Trigger expiration at t=200
         assert!(reg.check(200));
 
-        let mut cursor: *const WatchdogNode = ptr::null();
-        let mut expired_ids = [0u32; 4];
-        let mut count = 0;
+        let mut cursor = Cursor::start();
+        let node = reg.next_expired_node(&mut cursor).unwrap();
+        assert_eq!(node.id(), 1);
+        assert_eq!(node.timeout_interval_ms(), 150);
+        assert_eq!(node.last_touched_timestamp_ms(), 10);
+        assert!(reg.next_expired_node(&mut cursor).is_none());
+    }
 
-        while let Some(id) = reg.next_expired(&mut cursor) {
-            expired_ids[count] = id;
-            count += 1;
-            if count >= expired_ids.len() {
-                break;
-            }
+    #[test]
+    fn test_next_expiry_event() {
+        let mut reg = WatchdogRegistry::new();
+        let mut n = WatchdogNode::default();
+
+        unsafe {
+            WatchdogRegistry::assign_id(pin_mut(&mut n), 9);
+            reg.add(pin_mut(&mut n), 100, 0);
         }
 
-        // n3 (id=3) and n1 (id=1) should be expired; n2 (id=2) is healthy
-        assert_eq!(count, 2);
-        assert_eq!(expired_ids[0], 3); // head is n3
-        assert_eq!(expired_ids[1], 1); // tail is n1
+        let mut cursor = Cursor::start();
+        assert_eq!(reg.next_expiry_event(&mut cursor), None);
+
+        assert!(reg.check(250));
+        assert_eq!(
+            reg.next_expiry_event(&mut cursor),
+            Some(ExpiryEvent {
+                id: 9,
+                expired_at_ms: 250,
+                #[cfg(feature = "names")]
+                name: None,
+            })
+        );
+        assert_eq!(reg.next_expiry_event(&mut cursor), None);
     }
 
     #[test]
@@ -837,7 +8042,7 @@ mod tests {
         }
 
         // Don't call check — next_expired should return None
-        let mut cursor: *const WatchdogNode = ptr::null();
+        let mut cursor = Cursor::start();
         assert_eq!(reg.next_expired(&mut cursor), None);
     }
 
@@ -853,7 +8058,7 @@ mod tests {
         // Check at t=100 — all healthy
         assert!(!reg.check(100));
 
-        let mut cursor: *const WatchdogNode = ptr::null();
+        let mut cursor = Cursor::start();
         assert_eq!(reg.next_expired(&mut cursor), None);
     }
 
@@ -895,7 +8100,7 @@ mod tests {
         // next_expired() should only report n1.
         // Without the fix, n2 would also be reported because
         // 450_u32.wrapping_sub(460) = u32::MAX - 9, which > 200.
-        let mut cursor: *const WatchdogNode = ptr::null();
+        let mut cursor = Cursor::start();
         let mut expired_ids = [0u32; 4];
         let mut count = 0;
         while let Some(id) = reg.next_expired(&mut cursor) {
@@ -935,6 +8140,7 @@ mod tests {
         assert_eq!(n.timeout_interval_ms, 0);
         assert_eq!(n.last_touched_timestamp_ms, 0);
         assert_eq!(n.id, 0);
+        assert_eq!(n.warn_interval_ms, 0);
         assert!(n.next.is_null());
     }
 